@@ -0,0 +1,45 @@
+//! converting a day of instants one by one and as a batch, run with `cargo bench`
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_persian::{to_persian_batch, PersianDateTime, ToPersian};
+use std::hint::black_box;
+use std::time::{Duration as Elapsed, Instant};
+
+const COUNT: i64 = 1_000_000;
+
+fn time<T>(name: &str, f: impl Fn() -> T) -> Elapsed {
+    // the first run warms the caches
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..5 {
+        black_box(f());
+    }
+    let elapsed = start.elapsed() / 5;
+    println!("{name:<24} {elapsed:>12.2?}");
+    elapsed
+}
+
+fn main() {
+    let start = "2024-11-09 00:00:00 UTC".parse::<DateTime<Utc>>().unwrap();
+    // a little over two weeks, about 86 instants a minute
+    let instants: Vec<_> = (0..COUNT)
+        .map(|i| start + Duration::milliseconds(i * 1300))
+        .collect();
+
+    let to_persian = time("to_persian", || {
+        instants.iter().map(|i| i.to_persian()).collect::<Vec<_>>()
+    });
+    let from = time("PersianDateTime::from", || {
+        instants
+            .iter()
+            .map(|i| PersianDateTime::from(*i))
+            .collect::<Vec<_>>()
+    });
+    let batch = time("to_persian_batch", || to_persian_batch(&instants));
+
+    println!(
+        "batch speedup: {:.1}x over to_persian, {:.1}x over PersianDateTime::from",
+        to_persian.as_secs_f64() / batch.as_secs_f64(),
+        from.as_secs_f64() / batch.as_secs_f64(),
+    );
+}
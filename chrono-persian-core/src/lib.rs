@@ -0,0 +1,306 @@
+//! The persian (jalali) calendar arithmetic of `chrono-persian`, without `std`, allocations or
+//! dependencies, for firmware and `wasm32-unknown-unknown`
+//!
+//! every function is a `const fn` on plain `(year, month, day)` numbers, months and days start
+//! from 1, the inputs aren't validated, check them with [`is_valid_jalali`] first
+//! ```rust
+//!use chrono_persian_core::{days_in_month, gregorian_to_jalali, is_leap_year, jalali_to_gregorian};
+//!
+//!const NOWRUZ_1404: (i32, u32, u32) = jalali_to_gregorian(1404, 1, 1);
+//!assert_eq!(NOWRUZ_1404, (2025, 3, 21));
+//!assert_eq!(gregorian_to_jalali(2024, 11, 10), (1403, 8, 20));
+//!
+//!assert!(is_leap_year(1403));
+//!assert_eq!(days_in_month(1404, 12), 29);
+//! ```
+#![no_std]
+
+/// source: https://jdf.scr.ir
+pub const fn gregorian_to_jalali(gy: i32, gm: u32, gd: u32) -> (i32, u32, u32) {
+    const G_D_M: [i32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let gy2 = if gm > 2 { gy + 1 } else { gy };
+
+    let mut days = 355666 + (365 * gy) + (gy2 + 3).div_euclid(4) - (gy2 + 99).div_euclid(100)
+        + (gy2 + 399).div_euclid(400)
+        + gd as i32
+        + G_D_M[(gm - 1) as usize];
+
+    let mut jy = -1595 + (33 * days.div_euclid(12053));
+    days = days.rem_euclid(12053);
+    jy += 4 * (days / 1461);
+    days %= 1461;
+
+    if days > 365 {
+        jy += (days - 1) / 365;
+        days = (days - 1) % 365;
+    }
+
+    let jm = if days < 186 {
+        1 + (days / 31)
+    } else {
+        7 + ((days - 186) / 30)
+    };
+
+    let jd = if days < 186 {
+        1 + (days % 31)
+    } else {
+        1 + ((days - 186) % 30)
+    };
+
+    (jy, jm as u32, jd as u32)
+}
+
+/// source: https://jdf.scr.ir
+pub const fn jalali_to_gregorian(jy: i32, jm: u32, jd: u32) -> (i32, u32, u32) {
+    let jy = jy + 1595;
+    let mut days = -355668
+        + (365 * jy)
+        + (jy.div_euclid(33) * 8)
+        + ((jy.rem_euclid(33) + 3) / 4)
+        + jd as i32
+        + if jm < 7 {
+            (jm as i32 - 1) * 31
+        } else {
+            (jm as i32 - 7) * 30 + 186
+        };
+
+    let mut gy = 400 * days.div_euclid(146097);
+    days = days.rem_euclid(146097);
+    if days > 36524 {
+        days -= 1;
+        gy += 100 * (days / 36524);
+        days %= 36524;
+        if days >= 365 {
+            days += 1;
+        }
+    }
+
+    gy += 4 * (days / 1461);
+    days %= 1461;
+    if days > 365 {
+        gy += (days - 1) / 365;
+        days = (days - 1) % 365;
+    }
+
+    let leap = (gy % 4 == 0 && gy % 100 != 0) || gy % 400 == 0;
+    let month_days = [
+        31,
+        if leap { 29 } else { 28 },
+        31,
+        30,
+        31,
+        30,
+        31,
+        31,
+        30,
+        31,
+        30,
+        31,
+    ];
+    let mut gd = days + 1;
+    let mut gm = 0;
+    while gm < 11 && gd > month_days[gm] {
+        gd -= month_days[gm];
+        gm += 1;
+    }
+
+    (gy, gm as u32 + 1, gd as u32)
+}
+
+/// day of the week of a gregorian date, 0 is monday (sakamoto's method)
+pub const fn gregorian_weekday(gy: i32, gm: u32, gd: u32) -> u32 {
+    const T: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let y = if gm < 3 { gy - 1 } else { gy };
+    let from_sunday = (y + y.div_euclid(4) - y.div_euclid(100)
+        + y.div_euclid(400)
+        + T[(gm - 1) as usize]
+        + gd as i32)
+        .rem_euclid(7);
+    ((from_sunday + 6) % 7) as u32
+}
+
+/// number of days in a jalali month, from 29 to 31
+pub const fn days_in_month(jy: i32, jm: u32) -> u32 {
+    match jm {
+        1..=6 => 31,
+        7..=11 => 30,
+        _ if is_leap_year(jy) => 30,
+        _ => 29,
+    }
+}
+
+/// leap years of the 33-year cycle used by [`gregorian_to_jalali`]
+pub const fn is_leap_year(jy: i32) -> bool {
+    // `(jy + 1595) % 33` without overflowing, 1595 is 11 after 48 cycles
+    let r = (jy.rem_euclid(33) + 11) % 33;
+    r % 4 == 0 && r != 32
+}
+
+/// whether the month and day exist in the jalali year, the year isn't range checked
+/// ```rust
+///use chrono_persian_core::is_valid_jalali;
+///
+///assert!(is_valid_jalali(1403, 12, 30));
+///assert!(!is_valid_jalali(1404, 12, 30));
+///assert!(!is_valid_jalali(1403, 13, 1));
+/// ```
+pub const fn is_valid_jalali(jy: i32, jm: u32, jd: u32) -> bool {
+    1 <= jm && jm <= 12 && 1 <= jd && jd <= days_in_month(jy, jm)
+}
+
+/// The calendar fields [`persian_format!`] writes, built with [`FormatFields`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fields {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    /// hour, minute and second, `None` for a date
+    pub time: Option<(u32, u32, u32)>,
+}
+
+/// A value [`persian_format!`] can write
+pub trait FormatFields {
+    fn fields(&self) -> Fields;
+}
+
+/// a jalali `(year, month, day)`
+impl FormatFields for (i32, u32, u32) {
+    fn fields(&self) -> Fields {
+        Fields {
+            year: self.0,
+            month: self.1,
+            day: self.2,
+            time: None,
+        }
+    }
+}
+
+/// a jalali `(year, month, day, hour, minute, second)`
+impl FormatFields for (i32, u32, u32, u32, u32, u32) {
+    fn fields(&self) -> Fields {
+        Fields {
+            year: self.0,
+            month: self.1,
+            day: self.2,
+            time: Some((self.3, self.4, self.5)),
+        }
+    }
+}
+
+impl FormatFields for Fields {
+    fn fields(&self) -> Fields {
+        *self
+    }
+}
+
+/// whether `pattern` only has the specifiers of [`persian_format!`]
+pub const fn is_valid_pattern(pattern: &str) -> bool {
+    let bytes = pattern.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if i + 1 == bytes.len() {
+                return false;
+            }
+            match bytes[i + 1] {
+                b'Y' | b'm' | b'd' | b'H' | b'M' | b'S' | b'%' => {}
+                _ => return false,
+            }
+            i += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Write `fields` with `pattern` into `buf`, the runtime half of [`persian_format!`]
+///
+/// `None` if `buf` is too small or the pattern has a time specifier and `fields` no time
+pub fn write_pattern<'a>(buf: &'a mut [u8], pattern: &str, fields: &Fields) -> Option<&'a str> {
+    let mut len = 0;
+    let mut bytes = pattern.bytes();
+    while let Some(b) = bytes.next() {
+        if b != b'%' {
+            *buf.get_mut(len)? = b;
+            len += 1;
+            continue;
+        }
+        let (value, width) = match bytes.next()? {
+            b'Y' => {
+                if fields.year < 0 {
+                    *buf.get_mut(len)? = b'-';
+                    len += 1;
+                }
+                (fields.year.unsigned_abs(), 4)
+            }
+            b'm' => (fields.month, 2),
+            b'd' => (fields.day, 2),
+            b'H' => (fields.time?.0, 2),
+            b'M' => (fields.time?.1, 2),
+            b'S' => (fields.time?.2, 2),
+            _ => {
+                *buf.get_mut(len)? = b'%';
+                len += 1;
+                continue;
+            }
+        };
+        len += write_digits(buf.get_mut(len..)?, value, width)?;
+    }
+    // the pattern is a str and only ascii digits are added
+    core::str::from_utf8(&buf[..len]).ok()
+}
+
+/// write `value` zero padded to `width`, returns the length
+fn write_digits(buf: &mut [u8], value: u32, width: usize) -> Option<usize> {
+    let mut digits = [0u8; 10];
+    let mut n = value;
+    let mut count = 0;
+    while n > 0 || count == 0 {
+        digits[count] = b'0' + (n % 10) as u8;
+        n /= 10;
+        count += 1;
+    }
+    let len = count.max(width);
+    let out = buf.get_mut(..len)?;
+    for (i, slot) in out.iter_mut().enumerate() {
+        *slot = match len - i <= count {
+            true => digits[len - i - 1],
+            false => b'0',
+        };
+    }
+    Some(len)
+}
+
+/// Write a date into a byte buffer without allocating, the pattern is checked at compile time
+///
+/// the pattern takes `%Y` (at least 4 digits), `%m`, `%d`, `%H`, `%M`, `%S` (2 digits) and
+/// `%%`, the value is anything [`FormatFields`], such as a `(year, month, day)` tuple, the
+/// result is `None` when the buffer is too small or a time is missing
+/// ```rust
+///use chrono_persian_core::persian_format;
+///
+///let mut buf = [0u8; 32];
+///assert_eq!(persian_format!(buf, "%Y/%m/%d", (1403, 8, 20)), Some("1403/08/20"));
+///assert_eq!(persian_format!(buf, "%H:%M", (1403, 8, 20, 2, 8, 28)), Some("02:08"));
+///assert_eq!(persian_format!(buf, "%H:%M", (1403, 8, 20)), None);
+///
+///let mut small = [0u8; 4];
+///assert_eq!(persian_format!(small, "%Y/%m/%d", (1403, 8, 20)), None);
+/// ```
+/// an unknown specifier doesn't compile
+/// ```compile_fail
+///use chrono_persian_core::persian_format;
+///
+///let mut buf = [0u8; 32];
+///persian_format!(buf, "%Y/%q", (1403, 8, 20));
+/// ```
+#[macro_export]
+macro_rules! persian_format {
+    ($buf:expr, $pattern:literal, $value:expr) => {{
+        const _: () = assert!(
+            $crate::is_valid_pattern($pattern),
+            "unknown specifier in persian_format! pattern"
+        );
+        $crate::write_pattern(&mut $buf, $pattern, &$crate::FormatFields::fields(&$value))
+    }};
+}
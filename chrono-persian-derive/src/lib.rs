@@ -0,0 +1,513 @@
+//! Macros for `chrono-persian`, use them through the `derive` feature of that crate
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, LitStr, Type};
+
+/// Marks the `#[persian(format = "..", digits = "fa")]` fields of a struct to be (de)serialized
+/// as jalali strings, must be placed before `#[derive(Serialize, Deserialize)]`
+///
+/// see `chrono_persian::serde::format` for the details
+#[proc_macro_attribute]
+pub fn persian_serde(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(Span::call_site(), "`persian_serde` doesn't take arguments")
+            .into_compile_error()
+            .into();
+    }
+    let mut input = parse_macro_input!(item as DeriveInput);
+    match expand_serde(&mut input) {
+        Ok(functions) => quote!(#input #functions).into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Derives `Display` showing the date fields of a struct in jalali
+///
+/// see `chrono_persian::display` for the details
+#[proc_macro_derive(PersianDisplay, attributes(persian))]
+pub fn persian_display(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match expand_display(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// the options of a `#[persian(..)]` field attribute
+#[derive(Default)]
+struct FieldOptions {
+    format: Option<LitStr>,
+    persian_digits: bool,
+    skip: bool,
+}
+
+impl FieldOptions {
+    fn format(&self) -> TokenStream2 {
+        match &self.format {
+            Some(f) => quote!(::core::option::Option::Some(#f)),
+            None => quote!(::core::option::Option::None),
+        }
+    }
+
+    fn digits(&self) -> TokenStream2 {
+        if self.persian_digits {
+            quote!(::chrono_persian::Digits::Persian)
+        } else {
+            quote!(::chrono_persian::Digits::Latin)
+        }
+    }
+}
+
+fn struct_fields<'a>(
+    input: &'a mut DeriveInput,
+    macro_name: &str,
+) -> syn::Result<Option<&'a mut syn::punctuated::Punctuated<Field, syn::token::Comma>>> {
+    let Data::Struct(data) = &mut input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("`{macro_name}` only supports structs"),
+        ));
+    };
+    Ok(match &mut data.fields {
+        Fields::Named(f) => Some(&mut f.named),
+        Fields::Unnamed(f) => Some(&mut f.unnamed),
+        Fields::Unit => None,
+    })
+}
+
+fn expand_serde(input: &mut DeriveInput) -> syn::Result<TokenStream2> {
+    // `PersianDisplay` reads the same attributes, so they stay when it's derived too
+    let keep_attrs = derives_display(&input.attrs);
+    let (ident, generics) = (input.ident.clone(), input.generics.clone());
+    let Some(fields) = struct_fields(input, "persian_serde")? else {
+        return Ok(TokenStream2::new());
+    };
+
+    let mut functions = TokenStream2::new();
+    for (i, field) in fields.iter_mut().enumerate() {
+        let Some(options) = field_options(field)? else {
+            continue;
+        };
+        if !keep_attrs {
+            field.attrs.retain(|a| !a.path().is_ident("persian"));
+        }
+        if options.skip {
+            continue;
+        }
+        let name = match &field.ident {
+            Some(field) => format!("__persian_serde_{ident}_{field}"),
+            None => format!("__persian_serde_{ident}_{i}"),
+        };
+        let (ser_name, de_name) = (format_ident!("{name}_ser"), format_ident!("{name}_de"));
+        let (ser_path, de_path) = (
+            LitStr::new(&ser_name.to_string(), Span::call_site()),
+            LitStr::new(&de_name.to_string(), Span::call_site()),
+        );
+        field.attrs.push(syn::parse_quote!(
+            #[serde(serialize_with = #ser_path, deserialize_with = #de_path)]
+        ));
+        // `deserialize_with` makes serde require the field, a missing option is `None` again
+        let optional = option_inner(&field.ty).is_some();
+        if optional && !has_serde_default(&field.attrs) {
+            field.attrs.push(syn::parse_quote!(#[serde(default)]));
+        }
+
+        let (format, digits) = (options.format(), options.digits());
+        let ty = &field.ty;
+        let (ser, de) = match optional {
+            true => (quote!(serialize_option), quote!(deserialize_option)),
+            false => (quote!(serialize), quote!(deserialize)),
+        };
+        let (params, predicates) = field_generics(&generics, ty);
+        functions.extend(quote! {
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            fn #ser_name<#(#params,)* S: ::serde::Serializer>(
+                value: &#ty,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                #(#predicates,)*
+            {
+                ::chrono_persian::serde::format::#ser(value, #format, #digits, serializer)
+            }
+
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            fn #de_name<'de, #(#params,)* D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<#ty, D::Error>
+            where
+                #(#predicates,)*
+            {
+                ::chrono_persian::serde::format::#de(#format, deserializer)
+            }
+        });
+    }
+    Ok(functions)
+}
+
+fn expand_display(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let mut input = input.clone();
+    let layout = struct_layout(&input.attrs)?;
+    let ident = input.ident.clone();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = (
+        quote!(#impl_generics),
+        quote!(#ty_generics),
+        quote!(#where_clause),
+    );
+    let named = matches!(&input.data, Data::Struct(s) if matches!(s.fields, Fields::Named(_)));
+
+    let mut shown = Vec::new();
+    if let Some(fields) = struct_fields(&mut input, "PersianDisplay")? {
+        for (i, field) in fields.iter().enumerate() {
+            let options = field_options(field)?;
+            if options.as_ref().is_some_and(|o| o.skip) {
+                continue;
+            }
+            let member = match &field.ident {
+                Some(ident) => quote!(#ident),
+                None => {
+                    let index = syn::Index::from(i);
+                    quote!(#index)
+                }
+            };
+            let name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| i.to_string(), |i| i.to_string());
+            let value = if options.is_some() || is_date_type(&field.ty) {
+                let options = options.unwrap_or_default();
+                let (format, digits) = (options.format(), options.digits());
+                let helper = match option_inner(&field.ty) {
+                    Some(_) => quote!(option_field),
+                    None => quote!(field),
+                };
+                Value::Jalali(
+                    quote!(::chrono_persian::display::#helper(&self.#member, #format, #digits)),
+                )
+            } else {
+                Value::Plain(quote!(&self.#member))
+            };
+            shown.push((name, value));
+        }
+    }
+
+    let body = match layout {
+        Some(layout) => {
+            if !named {
+                return Err(syn::Error::new_spanned(
+                    layout,
+                    "`layout` needs a struct with named fields",
+                ));
+            }
+            let used = layout_names(&layout.value());
+            let args = shown
+                .iter()
+                .filter(|(name, _)| used.contains(name))
+                .map(|(name, value)| {
+                    let name = format_ident!("{name}");
+                    let value = value.tokens();
+                    quote!(#name = #value)
+                });
+            quote!(::core::write!(f, #layout, #(#args),*))
+        }
+        None => {
+            let writes = shown.iter().enumerate().map(|(i, (name, value))| {
+                let sep = if i == 0 { "" } else { ", " };
+                let label = if named {
+                    format!("{sep}{name}: ")
+                } else {
+                    sep.to_string()
+                };
+                let spec = match value {
+                    Value::Jalali(_) => "{}",
+                    Value::Plain(_) => "{:?}",
+                };
+                let fmt = LitStr::new(&format!("{label}{spec}"), Span::call_site());
+                let value = value.tokens();
+                quote!(::core::write!(f, #fmt, #value)?;)
+            });
+            let (open, close) = match (named, shown.is_empty()) {
+                (_, true) => ("", ""),
+                (true, false) => (" { ", " }"),
+                (false, false) => ("(", ")"),
+            };
+            let open = LitStr::new(&format!("{ident}{open}"), Span::call_site());
+            quote! {
+                f.write_str(#open)?;
+                #(#writes)*
+                f.write_str(#close)
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    })
+}
+
+/// how a field is shown by `PersianDisplay`
+enum Value {
+    /// a date shown in jalali
+    Jalali(TokenStream2),
+    /// any other field, shown with `Debug` in the default layout and `Display` in a custom one
+    Plain(TokenStream2),
+}
+
+impl Value {
+    fn tokens(&self) -> &TokenStream2 {
+        match self {
+            Value::Jalali(t) | Value::Plain(t) => t,
+        }
+    }
+}
+
+/// read the options of the `#[persian]` attribute of a field, if it has one
+fn field_options(field: &Field) -> syn::Result<Option<FieldOptions>> {
+    let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("persian")) else {
+        return Ok(None);
+    };
+    let mut options = FieldOptions::default();
+    if matches!(attr.meta, syn::Meta::Path(_)) {
+        return Ok(Some(options));
+    }
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("format") {
+            let format = meta.value()?.parse()?;
+            check_format(&format, &field.ty)?;
+            options.format = Some(format);
+        } else if meta.path.is_ident("digits") {
+            let digits: LitStr = meta.value()?.parse()?;
+            options.persian_digits = match digits.value().as_str() {
+                "fa" => true,
+                "en" => false,
+                _ => return Err(syn::Error::new_spanned(digits, "expected \"fa\" or \"en\"")),
+            };
+        } else if meta.path.is_ident("skip") {
+            options.skip = true;
+        } else {
+            return Err(meta.error("expected `format`, `digits` or `skip`"));
+        }
+        Ok(())
+    })?;
+    Ok(Some(options))
+}
+
+/// check a `format` like `chrono_persian::CompiledFormat` does, and that a date-only field
+/// isn't given a time
+fn check_format(format: &LitStr, ty: &Type) -> syn::Result<()> {
+    let pattern = format.value();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        match chars.next() {
+            Some('%' | 'Y' | 'm' | 'd' | 'B' | 'A') => {}
+            Some(spec @ ('H' | 'M' | 'S')) => {
+                if let Some(name) = date_only_type(ty) {
+                    return Err(syn::Error::new_spanned(
+                        format,
+                        format!("`%{spec}` needs a time but `{name}` only has a date"),
+                    ));
+                }
+            }
+            Some(spec) => {
+                return Err(syn::Error::new_spanned(
+                    format,
+                    format!("unsupported `%{spec}`, expected one of `%Y %m %d %H %M %S %B %A %%`"),
+                ))
+            }
+            None => return Err(syn::Error::new_spanned(format, "the pattern ends with `%`")),
+        }
+    }
+    Ok(())
+}
+
+/// the `layout` of a `#[persian(layout = "..")]` struct attribute
+fn struct_layout(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut layout = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("persian")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("layout") {
+                layout = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `layout`"))
+            }
+        })?;
+    }
+    Ok(layout)
+}
+
+/// the field names a layout refers to, such as `id` in `"#{id}"`
+fn layout_names(layout: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = layout;
+    while let Some(start) = rest.find(['{', '}']) {
+        let after = &rest[start + 1..];
+        if after.starts_with(&rest[start..start + 1]) {
+            rest = &after[1..];
+            continue;
+        }
+        if &rest[start..start + 1] == "{" {
+            if let Some(end) = after.find('}') {
+                let name = after[..end].split(':').next().unwrap_or_default();
+                names.push(name.trim().to_string());
+                rest = &after[end + 1..];
+                continue;
+            }
+        }
+        rest = after;
+    }
+    names
+}
+
+/// the generic parameters of the struct a field's type uses, without their defaults, and the
+/// where predicates on them
+///
+/// the functions (de)serializing the field take only those, so all of them can be inferred
+fn field_generics(
+    generics: &syn::Generics,
+    ty: &Type,
+) -> (Vec<syn::GenericParam>, Vec<syn::WherePredicate>) {
+    let used = idents(quote!(#ty));
+    let name = |param: &syn::GenericParam| match param {
+        syn::GenericParam::Lifetime(l) => l.lifetime.ident.to_string(),
+        syn::GenericParam::Type(t) => t.ident.to_string(),
+        syn::GenericParam::Const(c) => c.ident.to_string(),
+    };
+    let all: Vec<String> = generics.params.iter().map(name).collect();
+    let params = generics
+        .params
+        .iter()
+        .filter(|p| used.contains(&name(p)))
+        .cloned()
+        .map(|mut param| {
+            match &mut param {
+                syn::GenericParam::Type(t) => (t.eq_token, t.default) = (None, None),
+                syn::GenericParam::Const(c) => (c.eq_token, c.default) = (None, None),
+                syn::GenericParam::Lifetime(_) => {}
+            }
+            param
+        })
+        .collect();
+    let predicates = generics
+        .where_clause
+        .iter()
+        .flat_map(|w| &w.predicates)
+        .filter(|p| {
+            let mentioned = idents(quote!(#p));
+            mentioned
+                .iter()
+                .all(|i| !all.contains(i) || used.contains(i))
+        })
+        .cloned()
+        .collect();
+    (params, predicates)
+}
+
+/// the identifiers in some tokens, lifetimes without their `'`
+fn idents(tokens: TokenStream2) -> Vec<String> {
+    let mut found = Vec::new();
+    for tree in tokens {
+        match tree {
+            proc_macro2::TokenTree::Ident(i) => found.push(i.to_string()),
+            proc_macro2::TokenTree::Group(g) => found.extend(idents(g.stream())),
+            _ => {}
+        }
+    }
+    found
+}
+
+/// whether a field already has `#[serde(default)]`
+fn has_serde_default(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("serde"))
+        .any(|a| {
+            let mut found = false;
+            let _ = a.parse_nested_meta(|meta| {
+                found |= meta.path.is_ident("default");
+                // skip the value of options like `rename = ".."`
+                if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse::<syn::Expr>()?;
+                }
+                Ok(())
+            });
+            found
+        })
+}
+
+/// whether the struct derives `PersianDisplay`
+fn derives_display(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("derive"))
+        .any(|a| {
+            let mut found = false;
+            let _ = a.parse_nested_meta(|meta| {
+                found |= meta
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "PersianDisplay");
+                Ok(())
+            });
+            found
+        })
+}
+
+/// whether a field holds a date by its type name, or an option of one
+fn is_date_type(ty: &Type) -> bool {
+    const NAMES: [&str; 5] = [
+        "NaiveDate",
+        "NaiveDateTime",
+        "DateTime",
+        "PersianDate",
+        "PersianDateTime",
+    ];
+    let ty = option_inner(ty).unwrap_or(ty);
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|s| NAMES.iter().any(|n| s.ident == n))
+}
+
+/// the type name of a field holding a date without a time, or an option of one
+fn date_only_type(ty: &Type) -> Option<String> {
+    let Type::Path(path) = option_inner(ty).unwrap_or(ty) else {
+        return None;
+    };
+    let last = path.path.segments.last()?;
+    (last.ident == "NaiveDate" || last.ident == "PersianDate").then(|| last.ident.to_string())
+}
+
+/// the `T` of an `Option<T>` field
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let last = path.path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,82 @@
+/// The twelve animals of the traditional Iranian (Turkic) year cycle, e.g. `سال مار`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Animal {
+    Mouse,
+    Cow,
+    Leopard,
+    Rabbit,
+    Whale,
+    Snake,
+    Horse,
+    Sheep,
+    Monkey,
+    Hen,
+    Dog,
+    Pig,
+}
+
+impl Animal {
+    /// all animals in cycle order, starting with the mouse
+    pub const ALL: [Animal; 12] = [
+        Animal::Mouse,
+        Animal::Cow,
+        Animal::Leopard,
+        Animal::Rabbit,
+        Animal::Whale,
+        Animal::Snake,
+        Animal::Horse,
+        Animal::Sheep,
+        Animal::Monkey,
+        Animal::Hen,
+        Animal::Dog,
+        Animal::Pig,
+    ];
+
+    /// the farsi name, as used in `سال <name>`
+    pub fn name_fa(&self) -> &'static str {
+        match self {
+            Animal::Mouse => "موش",
+            Animal::Cow => "گاو",
+            Animal::Leopard => "پلنگ",
+            Animal::Rabbit => "خرگوش",
+            Animal::Whale => "نهنگ",
+            Animal::Snake => "مار",
+            Animal::Horse => "اسب",
+            Animal::Sheep => "گوسفند",
+            Animal::Monkey => "میمون",
+            Animal::Hen => "مرغ",
+            Animal::Dog => "سگ",
+            Animal::Pig => "خوک",
+        }
+    }
+
+    /// the english name
+    pub fn name_en(&self) -> &'static str {
+        match self {
+            Animal::Mouse => "Mouse",
+            Animal::Cow => "Cow",
+            Animal::Leopard => "Leopard",
+            Animal::Rabbit => "Rabbit",
+            Animal::Whale => "Whale",
+            Animal::Snake => "Snake",
+            Animal::Horse => "Horse",
+            Animal::Sheep => "Sheep",
+            Animal::Monkey => "Monkey",
+            Animal::Hen => "Hen",
+            Animal::Dog => "Dog",
+            Animal::Pig => "Pig",
+        }
+    }
+}
+
+/// Get the animal of a persian (jalali) year, the cycle starts at nowruz
+/// ```rust
+///use chrono_persian::{animal_of_year, Animal};
+///
+///assert_eq!(animal_of_year(1403), Animal::Whale);
+///assert_eq!(animal_of_year(1404).name_fa(), "مار");
+///assert_eq!(animal_of_year(1404).name_en(), "Snake");
+/// ```
+pub fn animal_of_year(year: i32) -> Animal {
+    Animal::ALL[(year + 5).rem_euclid(12) as usize]
+}
@@ -0,0 +1,103 @@
+use crate::PersianDate;
+
+/// Where the anniversary of 30 esfand falls in years without one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeapDayPolicy {
+    /// on 29 esfand, the last day of the same year
+    #[default]
+    LastDayOfEsfand,
+    /// on 1 farvardin, the first day of the next year
+    FirstOfFarvardin,
+}
+
+impl PersianDate {
+    /// Get the anniversary of this date (e.g. a birthday) in the persian year `year`
+    ///
+    /// only 30 esfand needs the `policy`, with [`LeapDayPolicy::FirstOfFarvardin`]
+    /// the result belongs to the next year. returns `None` when out of range
+    /// ```rust
+    ///use chrono_persian::{LeapDayPolicy, PersianDate};
+    ///
+    ///let birthday = PersianDate::from_ymd_opt(1399, 12, 30).unwrap();
+    ///
+    ///let a = birthday.anniversary(1404, LeapDayPolicy::LastDayOfEsfand).unwrap();
+    ///assert_eq!(a.to_string(), "1404-12-29");
+    ///
+    ///let b = birthday.anniversary(1404, LeapDayPolicy::FirstOfFarvardin).unwrap();
+    ///assert_eq!(b.to_string(), "1405-01-01");
+    ///
+    ///let c = birthday.anniversary(1403, LeapDayPolicy::FirstOfFarvardin).unwrap();
+    ///assert_eq!(c.to_string(), "1403-12-30");
+    /// ```
+    pub fn anniversary(self, year: i32, policy: LeapDayPolicy) -> Option<PersianDate> {
+        if let Some(date) = PersianDate::from_ymd_opt(year, self.month(), self.day()) {
+            return Some(date);
+        }
+        match policy {
+            LeapDayPolicy::LastDayOfEsfand => PersianDate::from_ymd_opt(year, 12, 29),
+            LeapDayPolicy::FirstOfFarvardin => {
+                PersianDate::from_ymd_opt(year.checked_add(1)?, 1, 1)
+            }
+        }
+    }
+
+    /// Get the number of whole years from this date (e.g. a birthday) to `date`
+    ///
+    /// returns `None` if `date` is before this date
+    /// ```rust
+    ///use chrono_persian::{LeapDayPolicy, PersianDate};
+    ///
+    ///let birthday = PersianDate::from_ymd_opt(1387, 12, 30).unwrap();
+    ///
+    /// // 1403 has a 30 esfand, so the birthday is still a day away
+    ///let day = PersianDate::from_ymd_opt(1403, 12, 29).unwrap();
+    ///assert_eq!(birthday.age_on(day, LeapDayPolicy::LastDayOfEsfand), Some(15));
+    ///
+    ///let day = PersianDate::from_ymd_opt(1404, 12, 29).unwrap();
+    ///assert_eq!(birthday.age_on(day, LeapDayPolicy::LastDayOfEsfand), Some(17));
+    ///assert_eq!(birthday.age_on(day, LeapDayPolicy::FirstOfFarvardin), Some(16));
+    /// ```
+    pub fn age_on(self, date: PersianDate, policy: LeapDayPolicy) -> Option<i32> {
+        if date < self {
+            return None;
+        }
+        let years = date.year() - self.year();
+        match self.anniversary(date.year(), policy) {
+            Some(anniversary) if date < anniversary => Some(years - 1),
+            _ => Some(years),
+        }
+    }
+}
+
+/// whether two dates have the same month and day, in any years
+pub fn same_month_day(a: PersianDate, b: PersianDate) -> bool {
+    (a.month(), a.day()) == (b.month(), b.day())
+}
+
+/// whether `date` is an anniversary of `anchor`, with 30 esfand moved by `policy` in the
+/// years without one, see [`PersianDate::anniversary`]
+/// ```rust
+///use chrono_persian::{matches_anniversary, same_month_day, LeapDayPolicy, PersianDate};
+///
+///let anchor = PersianDate::from_ymd_opt(1399, 12, 30).unwrap();
+///let last = PersianDate::from_ymd_opt(1404, 12, 29).unwrap();
+///let first = PersianDate::from_ymd_opt(1405, 1, 1).unwrap();
+///assert!(!same_month_day(anchor, last));
+///assert!(matches_anniversary(last, anchor, LeapDayPolicy::LastDayOfEsfand));
+///assert!(!matches_anniversary(first, anchor, LeapDayPolicy::LastDayOfEsfand));
+///assert!(matches_anniversary(first, anchor, LeapDayPolicy::FirstOfFarvardin));
+///
+/// // 1403 has a 30 esfand, so 29 esfand isn't the anniversary
+///let day = PersianDate::from_ymd_opt(1403, 12, 29).unwrap();
+///assert!(!matches_anniversary(day, anchor, LeapDayPolicy::LastDayOfEsfand));
+/// ```
+pub fn matches_anniversary(date: PersianDate, anchor: PersianDate, policy: LeapDayPolicy) -> bool {
+    if same_month_day(date, anchor) {
+        return true;
+    }
+    // with `FirstOfFarvardin` the anniversary of a year falls in the next one
+    [Some(date.year()), date.year().checked_sub(1)]
+        .into_iter()
+        .flatten()
+        .any(|year| anchor.anniversary(year, policy) == Some(date))
+}
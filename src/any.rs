@@ -0,0 +1,178 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{PersianDate, PersianDateTime};
+
+/// A value naming an instant, the input of [`to_persian_any`]
+pub trait IntoInstant {
+    /// the instant, `None` if chrono can't represent it
+    fn into_instant(self) -> Option<DateTime<Utc>>;
+}
+
+impl<Tz: TimeZone> IntoInstant for DateTime<Tz> {
+    fn into_instant(self) -> Option<DateTime<Utc>> {
+        Some(self.to_utc())
+    }
+}
+
+/// a wall clock in utc
+impl IntoInstant for NaiveDateTime {
+    fn into_instant(self) -> Option<DateTime<Utc>> {
+        Some(self.and_utc())
+    }
+}
+
+/// seconds since the unix epoch
+impl IntoInstant for i64 {
+    fn into_instant(self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp(self, 0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl IntoInstant for SystemTime {
+    fn into_instant(self) -> Option<DateTime<Utc>> {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => {
+                DateTime::from_timestamp(i64::try_from(after.as_secs()).ok()?, after.subsec_nanos())
+            }
+            Err(before) => {
+                let before = before.duration();
+                let secs = i64::try_from(before.as_secs()).ok()?;
+                // round towards the past so the nanoseconds are positive
+                match before.subsec_nanos() {
+                    0 => DateTime::from_timestamp(-secs, 0),
+                    nanos => DateTime::from_timestamp(-secs - 1, 1_000_000_000 - nanos),
+                }
+            }
+        }
+    }
+}
+
+impl IntoInstant for PersianDateTime {
+    fn into_instant(self) -> Option<DateTime<Utc>> {
+        Some(self.to_utc())
+    }
+}
+
+/// Convert any kind of instant to the persian date and time in iran, `None` if out of range
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::to_persian_any;
+///use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+///
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let expected = "1403-08-20 02:08:28 +03:30";
+///assert_eq!(to_persian_any(utc).unwrap().to_string(), expected);
+///assert_eq!(to_persian_any(utc.naive_utc()).unwrap().to_string(), expected);
+///assert_eq!(to_persian_any(1731191908_i64).unwrap().to_string(), expected);
+///
+///let system = UNIX_EPOCH + StdDuration::from_secs(1731191908);
+///assert_eq!(to_persian_any(system).unwrap().to_string(), expected);
+///let before = UNIX_EPOCH - StdDuration::from_millis(1500);
+///assert_eq!(to_persian_any(before).unwrap().to_utc().timestamp_millis(), -1500);
+///
+///assert!(to_persian_any(i64::MAX).is_none());
+/// ```
+pub fn to_persian_any<T: IntoInstant>(value: T) -> Option<PersianDateTime> {
+    value.into_instant().map(PersianDateTime::from)
+}
+
+/// Get the persian date in iran of any kind of instant, see [`to_persian_any`]
+pub fn to_persian_date_any<T: IntoInstant>(value: T) -> Option<PersianDate> {
+    to_persian_any(value).map(|p| p.date())
+}
+
+/// A gregorian or persian date and time, compared and hashed by the instant it names so
+/// both kinds can be sorted and deduplicated together
+/// ```rust
+///use chrono::{DateTime, FixedOffset};
+///use chrono_persian::{CalendarInstant, PersianDateTime};
+///
+///let gregorian = "2024-11-09T22:38:28Z".parse::<DateTime<FixedOffset>>().unwrap();
+///let persian = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 2, 8, 28).unwrap();
+///let earlier = PersianDateTime::from_ymd_hms_opt(1403, 8, 19, 0, 0, 0).unwrap();
+///assert_eq!(CalendarInstant::from(gregorian), CalendarInstant::from(persian));
+///
+///let mut events = vec![gregorian.into(), persian.into(), CalendarInstant::from(earlier)];
+///events.sort();
+///events.dedup();
+///assert_eq!(events.len(), 2);
+///assert_eq!(events[0].to_persian(), earlier);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum CalendarInstant {
+    Gregorian(DateTime<FixedOffset>),
+    Persian(PersianDateTime),
+}
+
+impl CalendarInstant {
+    /// the instant in utc
+    pub fn instant(&self) -> DateTime<Utc> {
+        match self {
+            CalendarInstant::Gregorian(g) => g.to_utc(),
+            CalendarInstant::Persian(p) => p.to_utc(),
+        }
+    }
+
+    /// the persian date and time in iran of the instant
+    pub fn to_persian(&self) -> PersianDateTime {
+        match self {
+            CalendarInstant::Gregorian(g) => PersianDateTime::from(*g),
+            CalendarInstant::Persian(p) => *p,
+        }
+    }
+}
+
+impl From<DateTime<FixedOffset>> for CalendarInstant {
+    fn from(value: DateTime<FixedOffset>) -> Self {
+        CalendarInstant::Gregorian(value)
+    }
+}
+
+impl From<DateTime<Utc>> for CalendarInstant {
+    fn from(value: DateTime<Utc>) -> Self {
+        CalendarInstant::Gregorian(value.fixed_offset())
+    }
+}
+
+impl From<PersianDateTime> for CalendarInstant {
+    fn from(value: PersianDateTime) -> Self {
+        CalendarInstant::Persian(value)
+    }
+}
+
+impl IntoInstant for CalendarInstant {
+    fn into_instant(self) -> Option<DateTime<Utc>> {
+        Some(self.instant())
+    }
+}
+
+impl PartialEq for CalendarInstant {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+impl Eq for CalendarInstant {}
+
+impl PartialOrd for CalendarInstant {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CalendarInstant {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
+
+impl Hash for CalendarInstant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instant().hash(state)
+    }
+}
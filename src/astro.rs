@@ -0,0 +1,179 @@
+//! Solstice and equinox instants that begin the persian seasons (requires the `astro` feature)
+//!
+//! the calculation follows Jean Meeus, *Astronomical Algorithms* (chapter 27), which is
+//! accurate to about a minute in the 20th and 21st centuries (gregorian). before 1900 and after
+//! 2150 the difference between terrestrial and universal time is only a rough long-term
+//! estimate, so the instants can be off by several minutes, and more the further they are
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::to_tehran;
+
+/// The four seasons of a persian year, each one starting at a solstice or equinox
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    /// spring, starts at the march equinox (nowruz)
+    Bahar,
+    /// summer, starts at the june solstice
+    Tabestan,
+    /// autumn, starts at the september equinox (around mehregan)
+    Paeez,
+    /// winter, starts at the december solstice, the night before is yalda
+    Zemestan,
+}
+
+impl Season {
+    /// all seasons in the order they appear in a persian year
+    pub const ALL: [Season; 4] = [
+        Season::Bahar,
+        Season::Tabestan,
+        Season::Paeez,
+        Season::Zemestan,
+    ];
+
+    /// the farsi name of the season
+    pub fn name_fa(&self) -> &'static str {
+        match self {
+            Season::Bahar => "بهار",
+            Season::Tabestan => "تابستان",
+            Season::Paeez => "پاییز",
+            Season::Zemestan => "زمستان",
+        }
+    }
+}
+
+/// Get the instant (in tehran time) at which `season` of the persian year `year` begins
+///
+/// returns `None` when the year is outside the supported range (379..=2379, the gregorian
+/// 1000 to 3000), see the module docs for the accuracy
+/// ```rust
+///use chrono_persian::astro::{season_start, Season};
+///
+///let yalda = season_start(1403, Season::Zemestan).unwrap();
+///assert_eq!(yalda.format("%Y-%m-%d %H:%M").to_string(), "2024-12-21 12:50");
+/// ```
+pub fn season_start(year: i32, season: Season) -> Option<DateTime<FixedOffset>> {
+    let gy = year + 621;
+    if !(1000..=3000).contains(&gy) {
+        return None;
+    }
+    let jde = mean_event(gy, season);
+    let t = (jde - 2451545.0) / 36525.0;
+    let w = (35999.373 * t - 2.47).to_radians();
+    let dl = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+    let jde = jde + 0.00001 * s / dl;
+    let jd = jde - delta_t(gy) / 86400.0;
+
+    let secs = ((jd - 2440587.5) * 86400.0).round() as i64;
+    let utc = DateTime::<Utc>::from_timestamp(secs, 0)?;
+    Some(to_tehran(&utc))
+}
+
+/// Get the moment of the new year (tahvil sal) of the persian year `year` in tehran time
+/// ```rust
+///use chrono_persian::astro::nowruz;
+///
+///let tahvil = nowruz(1403).unwrap();
+///assert_eq!(tahvil.format("%Y-%m-%d %H:%M").to_string(), "2024-03-20 06:36");
+/// ```
+pub fn nowruz(year: i32) -> Option<DateTime<FixedOffset>> {
+    season_start(year, Season::Bahar)
+}
+
+/// Get the start instants of all four seasons of a persian year, in [`Season::ALL`] order
+/// ```rust
+///use chrono_persian::astro::season_starts;
+///
+///let [bahar, tabestan, paeez, zemestan] = season_starts(1403).unwrap();
+///assert_eq!(tabestan.format("%Y-%m-%d %H:%M").to_string(), "2024-06-21 00:20");
+///assert_eq!(paeez.format("%Y-%m-%d %H:%M").to_string(), "2024-09-22 16:13");
+/// ```
+pub fn season_starts(year: i32) -> Option<[DateTime<FixedOffset>; 4]> {
+    Some([
+        season_start(year, Season::Bahar)?,
+        season_start(year, Season::Tabestan)?,
+        season_start(year, Season::Paeez)?,
+        season_start(year, Season::Zemestan)?,
+    ])
+}
+
+/// mean solstice/equinox in julian ephemeris days, meeus table 27.b
+fn mean_event(gy: i32, season: Season) -> f64 {
+    let y = (gy as f64 - 2000.0) / 1000.0;
+    let c = match season {
+        Season::Bahar => [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+        Season::Tabestan => [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+        Season::Paeez => [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+        Season::Zemestan => [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+    };
+    c[0] + y * (c[1] + y * (c[2] + y * (c[3] + y * c[4])))
+}
+
+/// difference between terrestrial and universal time in seconds (espenak & meeus)
+fn delta_t(gy: i32) -> f64 {
+    let y = gy as f64;
+    match gy {
+        1900..=1919 => {
+            let t = y - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        }
+        1920..=1940 => {
+            let t = y - 1920.0;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        1941..=1960 => {
+            let t = y - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        1961..=1985 => {
+            let t = y - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        1986..=2004 => {
+            let t = y - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        2005..=2049 => {
+            let t = y - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        }
+        2050..=2149 => -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2) - 0.5628 * (2150.0 - y),
+        _ => -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2),
+    }
+}
+
+/// periodic terms (a, b, c) of meeus table 27.c
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
@@ -0,0 +1,49 @@
+use alloc::{format, string::String};
+
+use crate::PersianDateTime;
+
+/// An instant as the attributes of a span or metric, the unix epoch in milliseconds for
+/// machines and the jalali string for dashboards
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::{PersianDateTime, TimeAttributes};
+///
+///let utc = "2024-11-09 22:38:28.5 UTC".parse::<DateTime<Utc>>().unwrap();
+///let attributes = TimeAttributes::new(&PersianDateTime::from(utc));
+///assert_eq!(attributes.epoch_ms, 1731191908500);
+///assert_eq!(attributes.jalali, "1403-08-20T02:08:28.500+03:30");
+///
+///let ((epoch_key, epoch), (jalali_key, _)) = attributes.key_values("order.created");
+///assert_eq!((epoch_key.as_str(), epoch), ("order.created.epoch_ms", 1731191908500));
+///assert_eq!(jalali_key, "order.created.jalali");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimeAttributes {
+    pub epoch_ms: i64,
+    /// as [`PersianDateTime::to_rfc3339`] writes it
+    pub jalali: String,
+}
+
+impl TimeAttributes {
+    pub fn new(value: &PersianDateTime) -> Self {
+        Self {
+            epoch_ms: value.to_utc().timestamp_millis(),
+            jalali: value.to_rfc3339(),
+        }
+    }
+
+    /// the attributes named `{prefix}.epoch_ms` and `{prefix}.jalali`, ready for an
+    /// opentelemetry `KeyValue` or a metrics label
+    pub fn key_values(&self, prefix: &str) -> ((String, i64), (String, String)) {
+        (
+            (format!("{prefix}.epoch_ms"), self.epoch_ms),
+            (format!("{prefix}.jalali"), self.jalali.clone()),
+        )
+    }
+}
+
+impl From<PersianDateTime> for TimeAttributes {
+    fn from(value: PersianDateTime) -> Self {
+        Self::new(&value)
+    }
+}
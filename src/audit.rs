@@ -0,0 +1,80 @@
+//! Opt-in double checking of conversions (requires the `audit` feature)
+//!
+//! the default algorithm and the secondary one (borkowski, as used by jalaali-js) agree on
+//! every year from 1178 to 1634, outside of that they disagree on some leap years.
+//! with this feature, conversions landing near such a contested year are converted again with
+//! the secondary algorithm and any difference is reported to the installed hook
+//! ```rust
+//!use chrono::NaiveDateTime;
+//!use chrono_persian::{audit, ToPersian};
+//!use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//!static REPORTS: AtomicUsize = AtomicUsize::new(0);
+//!audit::set_hook(|d| {
+//!    assert_eq!(d.primary, (1634, 12, 30));
+//!    assert_eq!(d.secondary, (1635, 1, 1));
+//!    REPORTS.fetch_add(1, Ordering::Relaxed);
+//!});
+//!
+//!let t = NaiveDateTime::parse_from_str("2256-03-20 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+//!t.to_persian().unwrap();
+//!assert_eq!(REPORTS.load(Ordering::Relaxed), 1);
+//! ```
+
+use std::sync::RwLock;
+
+use crate::calendar::{self, borkowski};
+
+type Hook = Box<dyn Fn(&Discrepancy) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// A conversion on which the default and the secondary algorithm disagree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Discrepancy {
+    /// the converted gregorian (year, month, day)
+    pub gregorian: (i32, u32, u32),
+    /// (year, month, day) as computed by the default algorithm, this is what the conversion returns
+    pub primary: (i32, u32, u32),
+    /// (year, month, day) as computed by the secondary algorithm
+    pub secondary: (i32, u32, u32),
+}
+
+/// Install the function that receives every [`Discrepancy`], replacing the previous one
+pub fn set_hook<F>(hook: F)
+where
+    F: Fn(&Discrepancy) + Send + Sync + 'static,
+{
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(hook));
+}
+
+/// Remove the installed hook, discrepancies are silently ignored afterwards
+pub fn clear_hook() {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// whether the leap status of the year or the one before it differs between the algorithms
+fn is_contested(jy: i32) -> bool {
+    let (min, max) = borkowski::RANGE;
+    (jy - 1..=jy)
+        .filter(|y| (min..max).contains(y))
+        .any(|y| calendar::is_leap_year(y) != borkowski::is_leap_year(y))
+}
+
+pub(crate) fn check(gregorian: (i32, u32, u32), primary: (i32, u32, u32)) {
+    let (min, max) = borkowski::RANGE;
+    if !(min + 1..max).contains(&(gregorian.0 - 621)) || !is_contested(primary.0) {
+        return;
+    }
+    let secondary = borkowski::gregorian_to_jalali(gregorian.0, gregorian.1, gregorian.2);
+    if secondary == primary {
+        return;
+    }
+    if let Some(hook) = HOOK.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        hook(&Discrepancy {
+            gregorian,
+            primary,
+            secondary,
+        });
+    }
+}
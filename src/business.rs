@@ -0,0 +1,249 @@
+use alloc::vec::Vec;
+use chrono::{NaiveTime, Weekday};
+use core::ops::Range;
+
+use crate::{PersianDate, PersianDays, PersianMonthOfYear, PersianMonths, Weekend};
+
+/// How a date that isn't a business day is moved to one, the rule taken by
+/// [`BusinessCalendar::roll`], [`BusinessCalendar::add_business_days`], [`due_date`] and
+/// [`BusinessCalendar::recurring`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Roll {
+    /// to the next business day
+    #[default]
+    Following,
+    /// to the previous business day
+    Preceding,
+    /// to the next business day, unless it's in the next month, then to the previous one
+    ModifiedFollowing,
+    /// to the previous business day, unless it's in the previous month, then to the next one
+    ModifiedPreceding,
+    /// not moved
+    Unadjusted,
+}
+
+/// The days a business is open, the weekend and the closed days
+/// ```rust
+///use chrono_persian::{BusinessCalendar, PersianDate, Roll};
+///
+///let calendar = BusinessCalendar::default();
+/// // a thursday
+///let date = PersianDate::from_ymd_opt(1403, 8, 24).unwrap();
+///assert!(!calendar.is_business_day(date));
+///assert_eq!(calendar.roll(date, Roll::Following).unwrap().to_string(), "1403-08-26");
+///assert_eq!(calendar.roll(date, Roll::Preceding).unwrap().to_string(), "1403-08-23");
+///
+/// // the last day of shahrivar is a friday
+///let date = PersianDate::from_ymd_opt(1402, 6, 31).unwrap();
+///assert_eq!(calendar.roll(date, Roll::ModifiedFollowing).unwrap().to_string(), "1402-06-29");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BusinessCalendar {
+    pub weekend: Weekend,
+    /// `(month, day)` closed every year
+    pub holidays: Vec<(u32, u32)>,
+    /// single closed days
+    pub closures: Vec<PersianDate>,
+    /// the hours of the days of the week that aren't open the whole day, in iran's time,
+    /// used by [`crate::sla_deadline`] and [`crate::business_hours_between`]
+    pub working_hours: Vec<(Weekday, Range<NaiveTime>)>,
+}
+
+impl BusinessCalendar {
+    /// A calendar closed only on the `weekend`
+    pub fn new(weekend: Weekend) -> Self {
+        Self {
+            weekend,
+            ..Default::default()
+        }
+    }
+
+    /// the same calendar open only in `hours` on each of the `days`
+    /// ```rust
+    ///use chrono::{NaiveTime, Weekday};
+    ///use chrono_persian::BusinessCalendar;
+    ///
+    ///let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+    ///let calendar = BusinessCalendar::default()
+    ///    .with_working_hours(&[Weekday::Sat, Weekday::Sun, Weekday::Mon], time(8)..time(16))
+    ///    .with_working_hours(&[Weekday::Wed], time(8)..time(12));
+    ///assert_eq!(calendar.working_hours(Weekday::Wed), Some(time(8)..time(12)));
+    ///assert_eq!(calendar.working_hours(Weekday::Tue), None);
+    /// ```
+    pub fn with_working_hours(mut self, days: &[Weekday], hours: Range<NaiveTime>) -> Self {
+        self.working_hours.retain(|(d, _)| !days.contains(d));
+        self.working_hours
+            .extend(days.iter().map(|d| (*d, hours.clone())));
+        self
+    }
+
+    /// the hours of a day of the week, `None` if it's open the whole day
+    pub fn working_hours(&self, day: Weekday) -> Option<Range<NaiveTime>> {
+        self.working_hours
+            .iter()
+            .find(|(d, _)| *d == day)
+            .map(|(_, hours)| hours.clone())
+    }
+
+    /// whether the business is open on `date`
+    pub fn is_business_day(&self, date: PersianDate) -> bool {
+        !date.is_weekend(self.weekend)
+            && !self.holidays.contains(&(date.month(), date.day()))
+            && !self.closures.contains(&date)
+    }
+
+    /// Get the first business day after `date`, `None` if there is none within a year
+    pub fn next_business_day(&self, date: PersianDate) -> Option<PersianDate> {
+        self.step(date, |d| d.checked_add(PersianDays::new(1)))
+    }
+
+    /// Get the last business day before `date`, `None` if there is none within a year
+    pub fn previous_business_day(&self, date: PersianDate) -> Option<PersianDate> {
+        self.step(date, |d| d.checked_sub(PersianDays::new(1)))
+    }
+
+    fn step(
+        &self,
+        date: PersianDate,
+        next: impl Fn(PersianDate) -> Option<PersianDate>,
+    ) -> Option<PersianDate> {
+        let mut day = date;
+        for _ in 0..366 {
+            day = next(day)?;
+            if self.is_business_day(day) {
+                return Some(day);
+            }
+        }
+        None
+    }
+
+    /// Move `date` to a business day with the `rule`, business days stay where they are
+    pub fn roll(&self, date: PersianDate, rule: Roll) -> Option<PersianDate> {
+        if self.is_business_day(date) {
+            return Some(date);
+        }
+        match rule {
+            Roll::Following => self.next_business_day(date),
+            Roll::Preceding => self.previous_business_day(date),
+            Roll::ModifiedFollowing => match self.next_business_day(date) {
+                Some(next) if next.month() == date.month() => Some(next),
+                _ => self.previous_business_day(date),
+            },
+            Roll::ModifiedPreceding => match self.previous_business_day(date) {
+                Some(previous) if previous.month() == date.month() => Some(previous),
+                _ => self.next_business_day(date),
+            },
+            Roll::Unadjusted => Some(date),
+        }
+    }
+
+    /// Move forward by `days` business days, a `date` that isn't a business day is first
+    /// moved with the `rule`
+    /// ```rust
+    ///use chrono_persian::{BusinessCalendar, PersianDate, Roll};
+    ///
+    ///let calendar = BusinessCalendar::default();
+    /// // a friday
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 25).unwrap();
+    ///let a = calendar.add_business_days(date, 2, Roll::Following).unwrap();
+    ///assert_eq!(a.to_string(), "1403-08-28");
+    ///let b = calendar.add_business_days(date, 2, Roll::Preceding).unwrap();
+    ///assert_eq!(b.to_string(), "1403-08-27");
+    /// ```
+    pub fn add_business_days(
+        &self,
+        date: PersianDate,
+        days: u32,
+        rule: Roll,
+    ) -> Option<PersianDate> {
+        let mut date = self.roll(date, rule)?;
+        for _ in 0..days {
+            date = self.next_business_day(date)?;
+        }
+        Some(date)
+    }
+
+    /// Iterate over the dates `every` months apart from `start`, each moved with the `rule`
+    ///
+    /// every date is counted from `start`, so a day clamped in a short month (or moved by the
+    /// rule) doesn't shift the ones after it
+    /// ```rust
+    ///use chrono_persian::{BusinessCalendar, PersianDate, PersianMonths, Roll};
+    ///
+    ///let calendar = BusinessCalendar::default();
+    ///let start = PersianDate::from_ymd_opt(1402, 6, 31).unwrap();
+    ///let dates: Vec<_> = calendar
+    ///    .recurring(start, PersianMonths::new(1), Roll::ModifiedFollowing)
+    ///    .take(4)
+    ///    .map(|d| d.to_string())
+    ///    .collect();
+    /// // the last days of shahrivar and azar 1402 are a friday and a thursday
+    ///assert_eq!(dates, ["1402-06-29", "1402-07-30", "1402-08-30", "1402-09-29"]);
+    /// ```
+    pub fn recurring(
+        &self,
+        start: PersianDate,
+        every: PersianMonths,
+        rule: Roll,
+    ) -> impl Iterator<Item = PersianDate> + '_ {
+        (0u32..)
+            .map_while(move |n| start.checked_add(PersianMonths::new(every.0.checked_mul(n)?)))
+            .filter_map(move |date| self.roll(date, rule))
+    }
+}
+
+/// Get the date something is due, `months` persian months and then `grace_days` days after
+/// `start`, moved to a business day with the `rule`
+///
+/// the day is clamped to the end of a shorter month, like in [`PersianDate::checked_add`]
+/// ```rust
+///use chrono_persian::{due_date, BusinessCalendar, PersianDate, PersianMonths, Roll};
+///
+///let calendar = BusinessCalendar::default();
+///let start = PersianDate::from_ymd_opt(1403, 5, 31).unwrap();
+///
+/// // 1403-07-30 is a monday
+///let a = due_date(start, PersianMonths::new(2), 0, &calendar, Roll::Following).unwrap();
+///assert_eq!(a.to_string(), "1403-07-30");
+///
+/// // 1403-08-03 is a thursday
+///let b = due_date(start, PersianMonths::new(2), 3, &calendar, Roll::Following).unwrap();
+///assert_eq!(b.to_string(), "1403-08-05");
+/// ```
+pub fn due_date(
+    start: PersianDate,
+    months: PersianMonths,
+    grace_days: u32,
+    calendar: &BusinessCalendar,
+    rule: Roll,
+) -> Option<PersianDate> {
+    let date = start
+        .checked_add(months)?
+        .checked_add(PersianDays::new(grace_days as u64))?;
+    calendar.roll(date, rule)
+}
+
+/// Get the business days of a persian month in `calendar`, such as the working saturdays to
+/// wednesdays for attendance sheets, `None` if the month isn't from 1 to 12
+/// ```rust
+///use chrono_persian::{working_days_in_month, BusinessCalendar};
+///
+///let calendar = BusinessCalendar {
+///    holidays: vec![(1, 1), (1, 2), (1, 3), (1, 4), (1, 12), (1, 13)],
+///    ..Default::default()
+///};
+/// // farvardin 1404 has 31 days, 9 in the thursday and friday weekends and 5 holidays on
+/// // other days
+///let days = working_days_in_month(1404, 1, &calendar).unwrap();
+///assert_eq!(days.len(), 17);
+///assert_eq!(days[0].to_string(), "1404-01-05");
+///assert!(working_days_in_month(1404, 13, &calendar).is_none());
+/// ```
+pub fn working_days_in_month(
+    year: i32,
+    month: u32,
+    calendar: &BusinessCalendar,
+) -> Option<Vec<PersianDate>> {
+    let days = PersianMonthOfYear::new(year, month)?.days();
+    Some(days.filter(|d| calendar.is_business_day(*d)).collect())
+}
@@ -0,0 +1,165 @@
+use chrono::NaiveTime;
+
+use crate::{PersianDate, PersianDateTime, PersianError, Resolve};
+
+/// a position in a byte string, digits are latin, persian or arabic, the latter two in utf-8
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl Cursor<'_> {
+    fn digit(&mut self) -> Option<u32> {
+        let (value, len) = match *self.bytes {
+            [b @ b'0'..=b'9', ..] => (b - b'0', 1),
+            // U+06F0 to U+06F9
+            [0xDB, b @ 0xB0..=0xB9, ..] => (b - 0xB0, 2),
+            // U+0660 to U+0669
+            [0xD9, b @ 0xA0..=0xA9, ..] => (b - 0xA0, 2),
+            _ => return None,
+        };
+        self.bytes = &self.bytes[len..];
+        Some(value as u32)
+    }
+
+    /// read 1 to `max` digits
+    fn number(&mut self, max: usize) -> Result<u32, PersianError> {
+        let mut value = self.digit().ok_or(PersianError::InvalidFormat)?;
+        for _ in 1..max {
+            match self.digit() {
+                Some(d) => value = value * 10 + d,
+                None => break,
+            }
+        }
+        Ok(value)
+    }
+
+    fn eat(&mut self, accept: &[u8]) -> Result<u8, PersianError> {
+        match *self.bytes {
+            [b, ..] if accept.contains(&b) => {
+                self.bytes = &self.bytes[1..];
+                Ok(b)
+            }
+            _ => Err(PersianError::InvalidFormat),
+        }
+    }
+
+    /// `year/month/day` with `/` or `-`
+    fn date(&mut self) -> Result<PersianDate, PersianError> {
+        let year = self.number(9)? as i32;
+        let sep = self.eat(b"/-")?;
+        let month = self.number(2)?;
+        self.eat(&[sep])?;
+        let day = self.number(2)?;
+        PersianDate::from_ymd_opt(year, month, day).ok_or(PersianError::InvalidJalaliDate {
+            year,
+            month,
+            day,
+        })
+    }
+}
+
+/// `dddd-dd-dd` or `dddd/dd/dd` in latin digits
+fn fixed_date(bytes: &[u8]) -> Option<Result<PersianDate, PersianError>> {
+    let [y0, y1, y2, y3, s0, m0, m1, s1, d0, d1] = *bytes else {
+        return None;
+    };
+    let fields = [y0, y1, y2, y3, m0, m1, d0, d1];
+    if !(fields.iter().all(u8::is_ascii_digit) && s0 == s1 && (s0 == b'-' || s0 == b'/')) {
+        return None;
+    }
+    let [y0, y1, y2, y3, m0, m1, d0, d1] = fields.map(|b| (b - b'0') as u32);
+    let year = (y0 * 1000 + y1 * 100 + y2 * 10 + y3) as i32;
+    let (month, day) = (m0 * 10 + m1, d0 * 10 + d1);
+    Some(
+        PersianDate::from_ymd_opt(year, month, day).ok_or(PersianError::InvalidJalaliDate {
+            year,
+            month,
+            day,
+        }),
+    )
+}
+
+impl PersianDate {
+    /// Parse `year/month/day` (or `year-month-day`) from bytes, such as a line of a log,
+    /// without checking the whole input is utf-8
+    ///
+    /// the digits can be latin, or persian or arabic in utf-8, surrounding ascii whitespace is
+    /// ignored
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianError};
+    ///
+    ///let date = PersianDate::parse_bytes(b"1403-08-20").unwrap();
+    ///assert_eq!(date, PersianDate::parse_bytes("۱۴۰۳/۸/۲۰\n".as_bytes()).unwrap());
+    ///
+    ///assert_eq!(PersianDate::parse_bytes(b"1403/01-01"), Err(PersianError::InvalidFormat));
+    ///assert_eq!(PersianDate::parse_bytes(b"\xff1403/01/01"), Err(PersianError::InvalidFormat));
+    ///assert_eq!(
+    ///    PersianDate::parse_bytes(b"1404/12/30"),
+    ///    Err(PersianError::InvalidJalaliDate { year: 1404, month: 12, day: 30 })
+    ///);
+    /// ```
+    pub fn parse_bytes(bytes: &[u8]) -> Result<Self, PersianError> {
+        let bytes = bytes.trim_ascii();
+        if let Some(date) = fixed_date(bytes) {
+            return date;
+        }
+        let mut cursor = Cursor { bytes };
+        let date = cursor.date()?;
+        match cursor.bytes.is_empty() {
+            true => Ok(date),
+            false => Err(PersianError::InvalidFormat),
+        }
+    }
+}
+
+impl PersianDateTime {
+    /// Parse a wall clock time in iran from bytes, a date like [`PersianDate::parse_bytes`]
+    /// optionally followed by a space or `T` and `hh:mm` or `hh:mm:ss`
+    /// ```rust
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let a = PersianDateTime::parse_bytes("۱۴۰۳/۰۸/۲۰ ۲۳:۰۷".as_bytes()).unwrap();
+    ///assert_eq!(a, PersianDateTime::parse_bytes(b"1403-08-20T23:07:00").unwrap());
+    ///assert_eq!(a.to_string(), "1403-08-20 23:07:00 +03:30");
+    ///
+    ///assert!(PersianDateTime::parse_bytes(b"1403-08-20 24:00").is_err());
+    /// ```
+    pub fn parse_bytes(bytes: &[u8]) -> Result<Self, PersianError> {
+        let mut cursor = Cursor {
+            bytes: bytes.trim_ascii(),
+        };
+        let date = match cursor.bytes.get(10) {
+            Some(b' ' | b'T') => fixed_date(&cursor.bytes[..10]),
+            _ => None,
+        };
+        let date = match date {
+            Some(date) => {
+                cursor.bytes = &cursor.bytes[10..];
+                date?
+            }
+            None => cursor.date()?,
+        };
+        let time = match cursor.bytes.is_empty() {
+            true => NaiveTime::MIN,
+            false => {
+                cursor.eat(b" T")?;
+                let hour = cursor.number(2)?;
+                cursor.eat(b":")?;
+                let minute = cursor.number(2)?;
+                let second = match cursor.eat(b":") {
+                    Ok(_) => cursor.number(2)?,
+                    Err(_) => 0,
+                };
+                if !cursor.bytes.is_empty() {
+                    return Err(PersianError::InvalidFormat);
+                }
+                NaiveTime::from_hms_opt(hour, minute, second).ok_or(PersianError::InvalidTime {
+                    hour,
+                    minute,
+                    second,
+                })?
+            }
+        };
+        Self::from_local(date, time, Resolve::Earliest).ok_or(PersianError::OutOfRange)
+    }
+}
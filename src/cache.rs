@@ -0,0 +1,90 @@
+use alloc::vec::Vec;
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::{to_tehran, PersianDate, PersianDateTime};
+
+const SECS_PER_DAY: i64 = 86400;
+
+/// A small least-recently-used cache of persian dates keyed by the day (in iran)
+///
+/// converting many instants from the same few days (such as log ingestion) skips
+/// the repeated calendar math, the capacity is expected to be small
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::DayCache;
+///
+///let mut cache = DayCache::new(4);
+///let a = cache
+///    .convert(&"2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap())
+///    .to_string();
+///let b = cache.convert_timestamp(1731191968).unwrap().to_string();
+///
+///assert_eq!(a, "1403-08-20 02:08:28 +03:30");
+///assert_eq!(b, "1403-08-20 02:09:28 +03:30");
+///assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DayCache {
+    capacity: usize,
+    /// (epoch day, date), most recently used last
+    entries: Vec<(i64, PersianDate)>,
+}
+
+impl DayCache {
+    /// create a cache holding up to `capacity` days, at least one
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// number of cached days
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// forget all cached days
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Convert an instant to the persian date and time in iran
+    pub fn convert(&mut self, instant: &DateTime<Utc>) -> PersianDateTime {
+        let local = to_tehran(instant);
+        let local_secs = instant.timestamp() + local.offset().local_minus_utc() as i64;
+        let date = self.lookup(local_secs.div_euclid(SECS_PER_DAY), &local);
+        PersianDateTime::from_parts(date, local)
+    }
+
+    /// Convert a unix timestamp in seconds, `None` if it's out of range
+    pub fn convert_timestamp(&mut self, secs: i64) -> Option<PersianDateTime> {
+        Some(self.convert(&DateTime::from_timestamp(secs, 0)?))
+    }
+
+    fn lookup(&mut self, day: i64, local: &DateTime<FixedOffset>) -> PersianDate {
+        if let Some(i) = self.entries.iter().rposition(|(d, _)| *d == day) {
+            let entry = self.entries.remove(i);
+            self.entries.push(entry);
+            return entry.1;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.remove(0);
+        }
+        let date = PersianDate::from_gregorian(&local.date_naive());
+        self.entries.push((day, date));
+        date
+    }
+}
+
+impl Default for DayCache {
+    /// a cache remembering only the last day
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
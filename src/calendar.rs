@@ -0,0 +1,81 @@
+pub(crate) use chrono_persian_core::{
+    days_in_month, gregorian_to_jalali, gregorian_weekday, is_leap_year,
+    is_valid_jalali as is_valid_ymd, jalali_to_gregorian,
+};
+
+/// secondary conversion used to cross-check the primary one, based on the
+/// algorithm by kazimierz m. borkowski (as used by jalaali-js)
+pub(crate) mod borkowski {
+    /// years at which the leap pattern of the 33-year cycles changes
+    const BREAKS: [i32; 20] = [
+        -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192, 2262, 2324,
+        2394, 2456, 3178,
+    ];
+
+    /// first and last (exclusive) jalali years the algorithm is defined for
+    pub(crate) const RANGE: (i32, i32) = (BREAKS[0], BREAKS[BREAKS.len() - 1]);
+
+    /// returns (years since the last leap year, gregorian year, march day of farvardin 1)
+    fn jal_cal(jy: i32) -> (i32, i32, i32) {
+        let gy = jy + 621;
+        let mut leap_j = -14;
+        let mut jp = BREAKS[0];
+        let mut jump = 0;
+        for &jm in &BREAKS[1..] {
+            jump = jm - jp;
+            if jy < jm {
+                break;
+            }
+            leap_j += (jump / 33) * 8 + (jump % 33) / 4;
+            jp = jm;
+        }
+        let mut n = jy - jp;
+        leap_j += (n / 33) * 8 + ((n % 33) + 3) / 4;
+        if jump % 33 == 4 && jump - n == 4 {
+            leap_j += 1;
+        }
+        let leap_g = gy / 4 - ((gy / 100 + 1) * 3) / 4 - 150;
+        let march = 20 + leap_j - leap_g;
+
+        if jump - n < 6 {
+            n = n - jump + ((jump + 4) / 33) * 33;
+        }
+        let mut leap = (((n + 1) % 33) - 1) % 4;
+        if leap == -1 {
+            leap = 4;
+        }
+        (leap, gy, march)
+    }
+
+    pub(crate) fn is_leap_year(jy: i32) -> bool {
+        jal_cal(jy).0 == 0
+    }
+
+    #[cfg(feature = "audit")]
+    fn g2d(gy: i32, gm: i32, gd: i32) -> i32 {
+        let d = ((gy + (gm - 8) / 6 + 100100) * 1461) / 4 + (153 * ((gm + 9) % 12) + 2) / 5 + gd
+            - 34840408;
+        d - (((gy + 100100 + (gm - 8) / 6) / 100) * 3) / 4 + 752
+    }
+
+    #[cfg(feature = "audit")]
+    pub(crate) fn gregorian_to_jalali(gy: i32, gm: u32, gd: u32) -> (i32, u32, u32) {
+        let jdn = g2d(gy, gm as i32, gd as i32);
+        let mut jy = gy - 621;
+        let (leap, _, march) = jal_cal(jy);
+        let mut k = jdn - g2d(gy, 3, march);
+        if k >= 0 {
+            if k <= 185 {
+                return (jy, (1 + k / 31) as u32, (k % 31 + 1) as u32);
+            }
+            k -= 186;
+        } else {
+            jy -= 1;
+            k += 179;
+            if leap == 1 {
+                k += 1;
+            }
+        }
+        (jy, (7 + k / 30) as u32, (k % 30 + 1) as u32)
+    }
+}
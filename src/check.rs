@@ -0,0 +1,150 @@
+use chrono::NaiveDate;
+use core::fmt;
+
+use crate::invariants::{self, Violation, Ymd};
+use crate::{calendar, leap_status, Algorithm, PersianDate};
+
+/// known conversions, gregorian (year, month, day) and jalali (year, month, day)
+const GOLDEN: [((i32, u32, u32), Ymd); 10] = [
+    ((1925, 3, 21), (1304, 1, 1)),
+    ((1970, 1, 1), (1348, 10, 11)),
+    ((1979, 2, 11), (1357, 11, 22)),
+    ((2000, 1, 1), (1378, 10, 11)),
+    ((2023, 3, 21), (1402, 1, 1)),
+    ((2024, 3, 20), (1403, 1, 1)),
+    ((2024, 11, 10), (1403, 8, 20)),
+    ((2025, 3, 20), (1403, 12, 30)),
+    ((2025, 3, 21), (1404, 1, 1)),
+    ((2026, 3, 21), (1405, 1, 1)),
+];
+
+/// the jalali years the invariants are checked over
+const CHECKED_YEARS: (i32, i32) = (1300, 1500);
+
+/// the leap years of the 33-year cycle and borkowski's algorithm agree from 1178 up to 1634
+const AGREEING_YEARS: (i32, i32) = (1178, 1634);
+
+/// The first failure [`self_check`] found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SelfCheckFailure {
+    /// a known conversion gave another date, `None` if it didn't convert
+    Golden {
+        gregorian: NaiveDate,
+        expected: Ymd,
+        found: Option<Ymd>,
+    },
+    /// a known conversion back to gregorian gave another date, `None` if it didn't convert
+    GoldenReverse {
+        jalali: Ymd,
+        expected: NaiveDate,
+        found: Option<NaiveDate>,
+    },
+    /// an invariant of the conversion is broken
+    Invariant(Violation),
+    /// the algorithms disagree on whether `year` is a leap year
+    Algorithm { year: i32 },
+}
+
+impl fmt::Display for SelfCheckFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelfCheckFailure::Golden {
+                gregorian,
+                expected: (y, m, d),
+                found,
+            } => {
+                write!(f, "{gregorian} should be {y:04}/{m:02}/{d:02} but ")?;
+                match found {
+                    Some((fy, fm, fd)) => write!(f, "converts to {fy:04}/{fm:02}/{fd:02}"),
+                    None => f.write_str("doesn't convert"),
+                }
+            }
+            SelfCheckFailure::GoldenReverse {
+                jalali: (y, m, d),
+                expected,
+                found,
+            } => {
+                write!(f, "{y:04}/{m:02}/{d:02} should be {expected} but ")?;
+                match found {
+                    Some(found) => write!(f, "converts to {found}"),
+                    None => f.write_str("doesn't convert"),
+                }
+            }
+            SelfCheckFailure::Invariant(violation) => violation.fmt(f),
+            SelfCheckFailure::Algorithm { year } => {
+                write!(
+                    f,
+                    "the algorithms disagree on whether {year} is a leap year"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for SelfCheckFailure {}
+
+impl From<Violation> for SelfCheckFailure {
+    fn from(value: Violation) -> Self {
+        SelfCheckFailure::Invariant(value)
+    }
+}
+
+fn to_jalali(date: NaiveDate) -> Ymd {
+    let p = PersianDate::from(date);
+    (p.year(), p.month(), p.day())
+}
+
+/// Check the calendar engine at runtime, such as at the startup of a service that must not
+/// run with a broken build
+///
+/// it runs the embedded known conversions, the [`invariants`] over the jalali years 1300 to
+/// 1500 and compares the leap years of the [`Algorithm`]s where they should agree, it takes
+/// a few milliseconds in release builds
+/// ```rust
+///use chrono_persian::self_check;
+///
+///assert_eq!(self_check(), Ok(()));
+/// ```
+pub fn self_check() -> Result<(), SelfCheckFailure> {
+    for ((gy, gm, gd), expected) in GOLDEN {
+        let gregorian = NaiveDate::from_ymd_opt(gy, gm, gd).expect("golden dates are valid");
+        let found = to_jalali(gregorian);
+        if found != expected {
+            return Err(SelfCheckFailure::Golden {
+                gregorian,
+                expected,
+                found: Some(found),
+            });
+        }
+        let back = PersianDate::from_ymd_opt(expected.0, expected.1, expected.2)
+            .and_then(PersianDate::to_gregorian);
+        if back != Some(gregorian) {
+            return Err(SelfCheckFailure::GoldenReverse {
+                jalali: expected,
+                expected: gregorian,
+                found: back,
+            });
+        }
+    }
+
+    let (first, last) = CHECKED_YEARS;
+    let start = PersianDate::from_ymd_unchecked(first, 1, 1);
+    let end = PersianDate::from_ymd_unchecked(last, 12, calendar::days_in_month(last, 12));
+    let days = start.to_gregorian().expect("checked years are in range")
+        ..=end.to_gregorian().expect("checked years are in range");
+    let to_gregorian = |y, m, d| PersianDate::from_ymd_opt(y, m, d)?.to_gregorian();
+    let weekday = |y, m, d| PersianDate::from_ymd_unchecked(y, m, d).weekday();
+    invariants::round_trip(days.clone(), to_jalali, to_gregorian)?;
+    invariants::monotonic(days.clone(), to_jalali)?;
+    invariants::weekday_continuity(days.clone(), to_jalali, weekday)?;
+    invariants::month_lengths(days, to_jalali, calendar::days_in_month)?;
+
+    let (first, last) = AGREEING_YEARS;
+    if let Some(year) = (first..last).find(|&y| {
+        leap_status(y, Algorithm::ThirtyThreeYear) != leap_status(y, Algorithm::Borkowski)
+    }) {
+        return Err(SelfCheckFailure::Algorithm { year });
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command};
+use std::ffi::OsStr;
+
+use crate::PersianDate;
+
+/// A clap value parser for [`PersianDate`] arguments (requires the `clap` feature)
+///
+/// it's also what `clap::value_parser!(PersianDate)` uses
+/// ```rust
+///use chrono_persian::{PersianDate, PersianDateParser};
+///use clap::{Arg, Command};
+///
+///let cmd = Command::new("report")
+///    .arg(Arg::new("from").long("from").value_parser(PersianDateParser))
+///    .arg(Arg::new("to").long("to").value_parser(clap::value_parser!(PersianDate)));
+///
+///let m = cmd.clone().try_get_matches_from(["report", "--from", "1403/01/01", "--to", "1403/06/31"]).unwrap();
+///assert_eq!(m.get_one::<PersianDate>("to").unwrap().to_string(), "1403-06-31");
+///
+///let err = cmd.try_get_matches_from(["report", "--from", "1403/12/31"]).unwrap_err();
+///assert!(err.to_string().contains("1403/12/31 is not a valid jalali date"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PersianDateParser;
+
+impl TypedValueParser for PersianDateParser {
+    type Value = PersianDate;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<PersianDate, clap::Error> {
+        let value = value.to_string_lossy();
+        value.parse().map_err(|e| {
+            let arg = arg
+                .map(|a| a.to_string())
+                .unwrap_or_else(|| "...".to_owned());
+            clap::Error::raw(
+                ErrorKind::ValueValidation,
+                format!(
+                    "invalid value '{value}' for '{arg}': {e}\n\n  tip: use year/month/day, e.g. 1403/08/20\n"
+                ),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for PersianDate {
+    type Parser = PersianDateParser;
+
+    fn value_parser() -> PersianDateParser {
+        PersianDateParser
+    }
+}
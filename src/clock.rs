@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+
+use crate::{to_tehran, PersianDate, PersianDateTime};
+
+/// A source of the current time, so code checking dates against "now" can be tested
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// today's date in iran
+    fn today_in_tehran(&self) -> PersianDate {
+        PersianDate::from_gregorian(&to_tehran(&self.now()).date_naive())
+    }
+}
+
+/// The system's clock
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that is stopped at the given instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl PersianDate {
+    /// Get today's date in iran
+    #[cfg(feature = "std")]
+    pub fn today() -> PersianDate {
+        SystemClock.today_in_tehran()
+    }
+
+    /// whether the date is before today in iran
+    #[cfg(feature = "std")]
+    pub fn is_past(&self) -> bool {
+        self.is_past_with(&SystemClock)
+    }
+
+    /// whether the date is after today in iran
+    #[cfg(feature = "std")]
+    pub fn is_future(&self) -> bool {
+        self.is_future_with(&SystemClock)
+    }
+
+    /// whether the date is today in iran
+    #[cfg(feature = "std")]
+    pub fn is_today_in_tehran(&self) -> bool {
+        self.is_today_in_tehran_with(&SystemClock)
+    }
+
+    /// like [`PersianDate::is_past`] but with the given clock
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::{FixedClock, PersianDate};
+    ///
+    /// // 1403-08-20 02:08:28 in tehran
+    ///let clock = FixedClock("2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap());
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///assert!(date.is_today_in_tehran_with(&clock));
+    ///assert!(!date.is_past_with(&clock));
+    ///assert!(PersianDate::from_ymd_opt(1403, 8, 19).unwrap().is_past_with(&clock));
+    ///assert!(PersianDate::from_ymd_opt(1403, 8, 21).unwrap().is_future_with(&clock));
+    /// ```
+    pub fn is_past_with<C: Clock + ?Sized>(&self, clock: &C) -> bool {
+        *self < clock.today_in_tehran()
+    }
+
+    /// like [`PersianDate::is_future`] but with the given clock
+    pub fn is_future_with<C: Clock + ?Sized>(&self, clock: &C) -> bool {
+        *self > clock.today_in_tehran()
+    }
+
+    /// like [`PersianDate::is_today_in_tehran`] but with the given clock
+    pub fn is_today_in_tehran_with<C: Clock + ?Sized>(&self, clock: &C) -> bool {
+        *self == clock.today_in_tehran()
+    }
+}
+
+impl PersianDateTime {
+    /// Get the current persian date and time in iran
+    #[cfg(feature = "std")]
+    pub fn now() -> PersianDateTime {
+        PersianDateTime::from(Utc::now())
+    }
+
+    /// whether the instant has passed
+    #[cfg(feature = "std")]
+    pub fn is_past(&self) -> bool {
+        self.is_past_with(&SystemClock)
+    }
+
+    /// whether the instant is yet to come
+    #[cfg(feature = "std")]
+    pub fn is_future(&self) -> bool {
+        self.is_future_with(&SystemClock)
+    }
+
+    /// whether the instant is on today's date in iran
+    #[cfg(feature = "std")]
+    pub fn is_today_in_tehran(&self) -> bool {
+        self.is_today_in_tehran_with(&SystemClock)
+    }
+
+    /// like [`PersianDateTime::is_past`] but with the given clock
+    /// ```rust
+    ///use chrono::{DateTime, Duration, Utc};
+    ///use chrono_persian::{FixedClock, PersianDateTime};
+    ///
+    ///let now = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let clock = FixedClock(now);
+    ///
+    ///let expiry = PersianDateTime::from(now + Duration::hours(1));
+    ///assert!(expiry.is_future_with(&clock));
+    ///assert!(expiry.is_today_in_tehran_with(&clock));
+    ///assert!((expiry - Duration::hours(2)).is_past_with(&clock));
+    /// ```
+    pub fn is_past_with<C: Clock + ?Sized>(&self, clock: &C) -> bool {
+        self.to_utc() < clock.now()
+    }
+
+    /// like [`PersianDateTime::is_future`] but with the given clock
+    pub fn is_future_with<C: Clock + ?Sized>(&self, clock: &C) -> bool {
+        self.to_utc() > clock.now()
+    }
+
+    /// like [`PersianDateTime::is_today_in_tehran`] but with the given clock
+    pub fn is_today_in_tehran_with<C: Clock + ?Sized>(&self, clock: &C) -> bool {
+        PersianDateTime::from(self.to_utc()).date() == clock.today_in_tehran()
+    }
+}
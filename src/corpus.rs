@@ -0,0 +1,146 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::PersianDate;
+
+/// A string of a fuzz corpus
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CorpusEntry {
+    pub input: String,
+    /// the date [`PersianDate::from_str`](core::str::FromStr::from_str) reads from the string,
+    /// `None` when it rejects it
+    pub date: Option<PersianDate>,
+}
+
+/// dates around the edges of months, leap years and the 33-year cycle
+const VALID: [(i32, u32, u32); 10] = [
+    (1403, 8, 20),
+    (1403, 1, 1),
+    (1403, 6, 31),
+    (1403, 7, 30),
+    (1403, 12, 30),
+    (1402, 12, 29),
+    (1321, 12, 30),
+    (1, 1, 1),
+    (999, 9, 9),
+    (9999, 12, 29),
+];
+
+/// components that look like a date but aren't one
+const INVALID: [(i32, u32, u32); 8] = [
+    (1404, 12, 30),
+    (1403, 7, 31),
+    (1403, 13, 1),
+    (1403, 0, 10),
+    (1403, 8, 0),
+    (1403, 1, 32),
+    (1403, 12, 31),
+    (1403, 99, 99),
+];
+
+const SEPARATORS: [&str; 5] = ["/", "-", ".", " ", "\u{200c}/"];
+
+/// the zero of latin, persian and arabic-indic digits, arabic ones are often mixed in by
+/// keyboards and ocr
+const ZEROS: [char; 3] = ['0', '۰', '٠'];
+
+/// Make a corpus of jalali date strings to seed fuzzers and integration tests with
+///
+/// it mixes latin, persian and arabic digits, separators, padding and both valid and
+/// near-valid dates (impossible days, missing or extra parts, stray whitespace), the corpus is
+/// the same on every call. each entry is labeled with what the [`PersianDate`] parser reads,
+/// which rejects persian digits and separators other than `/` and `-`
+/// ```rust
+///use chrono_persian::{fuzz_corpus, PersianDate};
+///
+///let corpus = fuzz_corpus();
+///assert!(corpus.iter().any(|e| e.input == "1403/08/20" && e.date.is_some()));
+///assert!(corpus.iter().any(|e| e.input == "۱۴۰۳/۰۸/۲۰" && e.date.is_none()));
+///assert!(corpus.iter().any(|e| e.input == "1404-12-30" && e.date.is_none()));
+///
+///for entry in &corpus {
+///    assert_eq!(entry.input.parse::<PersianDate>().ok(), entry.date);
+///}
+/// ```
+pub fn fuzz_corpus() -> Vec<CorpusEntry> {
+    let mut inputs = Vec::new();
+    for &(y, m, d) in VALID.iter().chain(&INVALID) {
+        for sep in SEPARATORS {
+            for padded in [true, false] {
+                let text = if padded {
+                    format!("{y:04}{sep}{m:02}{sep}{d:02}")
+                } else {
+                    format!("{y}{sep}{m}{sep}{d}")
+                };
+                for zero in ZEROS {
+                    inputs.push(with_digits(&text, zero));
+                }
+            }
+        }
+        let text = format!("{y:04}/{m:02}/{d:02}");
+        // persian and latin digits in the same string
+        let mixed: String = text
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match i % 2 {
+                0 => with_digits(&c.to_string(), ZEROS[1]),
+                _ => c.to_string(),
+            })
+            .collect();
+        inputs.push(mixed);
+        // the same date with stray whitespace or padding
+        for input in [
+            format!(" {text}"),
+            format!("{text} "),
+            format!("{y:04}/{m:03}/{d:02}"),
+        ] {
+            inputs.push(input);
+        }
+        for input in [
+            format!("{text}/"),
+            format!("{text}/01"),
+            format!("{y:04}/{m:02}"),
+            format!("{y:04}//{m:02}/{d:02}"),
+            format!("{text}x"),
+            format!("-{text}"),
+            format!("+{text}"),
+            format!("\u{200f}{text}"),
+        ] {
+            inputs.push(input);
+        }
+    }
+    for junk in [
+        "",
+        " ",
+        "/",
+        "//",
+        "--",
+        "0/0/0",
+        "۱۴۰۳",
+        "1403/aban/20",
+        "٢٠٢٤",
+    ] {
+        inputs.push(junk.to_owned());
+    }
+    inputs
+        .into_iter()
+        .map(|input| CorpusEntry {
+            date: input.parse().ok(),
+            input,
+        })
+        .collect()
+}
+
+/// rewrite the latin digits of `s` with the digits starting at `zero`
+fn with_digits(s: &str, zero: char) -> String {
+    s.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) if c.is_ascii_digit() => char::from_u32(zero as u32 + d).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
@@ -0,0 +1,105 @@
+use chrono::Weekday;
+
+use crate::PersianDateTime;
+
+/// How many instants fell on each day of the week, see [`count_by_weekday`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WeekdayCounts {
+    /// from saturday
+    counts: [u64; 7],
+}
+
+fn days_from_saturday(weekday: Weekday) -> usize {
+    (weekday.num_days_from_monday() as usize + 2) % 7
+}
+
+impl WeekdayCounts {
+    pub fn get(&self, weekday: Weekday) -> u64 {
+        self.counts[days_from_saturday(weekday)]
+    }
+
+    /// the days and their counts in the order of the persian week, from saturday
+    pub fn iter(&self) -> impl Iterator<Item = (Weekday, u64)> + '_ {
+        let mut weekday = Weekday::Sat;
+        self.counts.iter().map(move |count| {
+            let day = weekday;
+            weekday = weekday.succ();
+            (day, *count)
+        })
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+}
+
+/// How many instants fell in each persian month, see [`count_by_month`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MonthCounts {
+    /// from farvardin
+    counts: [u64; 12],
+}
+
+impl MonthCounts {
+    /// the count of a month from 1 to 12, 0 for other months
+    pub fn get(&self, month: u32) -> u64 {
+        let index = month.wrapping_sub(1) as usize;
+        self.counts.get(index).copied().unwrap_or(0)
+    }
+
+    /// the months from 1 to 12 and their counts
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+        (1..=12).map(|m| (m, self.get(m)))
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+}
+
+/// Count the instants by the day of the week they fall on in iran's time
+/// ```rust
+///use chrono::{DateTime, Duration, Utc, Weekday};
+///use chrono_persian::count_by_weekday;
+///
+///let start = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let orders = [start, start + Duration::hours(1), start + Duration::days(1)];
+///let counts = count_by_weekday(orders);
+///assert_eq!(counts.get(Weekday::Sun), 2);
+///assert_eq!(counts.iter().next(), Some((Weekday::Sat, 0)));
+///assert_eq!(counts.total(), 3);
+/// ```
+pub fn count_by_weekday<I>(instants: I) -> WeekdayCounts
+where
+    I: IntoIterator,
+    I::Item: Into<PersianDateTime>,
+{
+    let mut counts = WeekdayCounts::default();
+    for instant in instants {
+        counts.counts[days_from_saturday(instant.into().date().weekday())] += 1;
+    }
+    counts
+}
+
+/// Count the instants by the persian month they fall in, in iran's time, whatever their year
+/// ```rust
+///use chrono::{DateTime, Duration, Utc};
+///use chrono_persian::count_by_month;
+///
+///let start = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let counts = count_by_month([start, start + Duration::days(30), start + Duration::days(365)]);
+///assert_eq!(counts.get(8), 2);
+///assert_eq!(counts.get(9), 1);
+///assert_eq!(counts.get(13), 0);
+/// ```
+pub fn count_by_month<I>(instants: I) -> MonthCounts
+where
+    I: IntoIterator,
+    I::Item: Into<PersianDateTime>,
+{
+    let mut counts = MonthCounts::default();
+    for instant in instants {
+        counts.counts[instant.into().date().month() as usize - 1] += 1;
+    }
+    counts
+}
@@ -0,0 +1,164 @@
+//! Streaming conversion of a date column in csv files (requires the `csv` feature)
+//!
+//! rows are read, converted and written one at a time so files of any size can be rewritten,
+//! empty cells are kept as they are
+//! ```rust
+//!use chrono_persian::csv::{convert_csv_column, convert_csv_column_to_gregorian};
+//!
+//!let input = "id,created\n1,2024-11-10\n2,\n3,2025-03-21\n";
+//!let mut output = Vec::new();
+//!let converted = convert_csv_column(input.as_bytes(), &mut output, "created", "%Y-%m-%d", "%Y/%m/%d").unwrap();
+//!assert_eq!(converted, 2);
+//!assert_eq!(String::from_utf8(output.clone()).unwrap(), "id,created\n1,1403/08/20\n2,\n3,1404/01/01\n");
+//!
+//!let mut back = Vec::new();
+//!convert_csv_column_to_gregorian(output.as_slice(), &mut back, "created", "%Y/%m/%d", "%Y-%m-%d").unwrap();
+//!assert_eq!(String::from_utf8(back).unwrap(), input);
+//! ```
+
+use chrono::format::StrftimeItems;
+use chrono::{NaiveDate, NaiveDateTime};
+use std::fmt::{self, Write as _};
+use std::io::{Read, Write};
+
+use crate::format::{compile, parse_items, write_items, Item};
+use crate::{Digits, PersianDate};
+
+/// Reasons a csv column can't be converted
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CsvError {
+    /// reading or writing the csv failed
+    Csv(::csv::Error),
+    /// the header has no column with this name
+    MissingColumn(String),
+    /// a pattern is invalid, or asks for a time the values don't have
+    InvalidPattern,
+    /// a cell doesn't match the input pattern, `line` starts at 1
+    InvalidDate { line: u64, value: String },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(e) => write!(f, "csv error: {e}"),
+            CsvError::MissingColumn(name) => write!(f, "there is no column named `{name}`"),
+            CsvError::InvalidPattern => f.write_str("invalid or unsupported format pattern"),
+            CsvError::InvalidDate { line, value } => {
+                write!(f, "line {line}: `{value}` doesn't match the input pattern")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Csv(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(value: ::csv::Error) -> Self {
+        CsvError::Csv(value)
+    }
+}
+
+/// Rewrite the gregorian dates of `column` as jalali ones, returns the number of converted cells
+///
+/// `in_fmt` is a chrono pattern, with or without a time, `out_fmt` is a jalali pattern
+/// (`%Y %m %d %H %M %S`). the time is kept as it is, no timezone is involved
+pub fn convert_csv_column<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    column: &str,
+    in_fmt: &str,
+    out_fmt: &str,
+) -> Result<u64, CsvError> {
+    check_chrono_pattern(in_fmt)?;
+    let out = compile(out_fmt).map_err(|_| CsvError::InvalidPattern)?;
+    rewrite(reader, writer, column, |value| {
+        let (date, time) = match NaiveDateTime::parse_from_str(value, in_fmt) {
+            Ok(dt) => (dt.date(), Some(dt.time())),
+            Err(_) => (NaiveDate::parse_from_str(value, in_fmt).ok()?, None),
+        };
+        let mut s = String::new();
+        write_items(&mut s, &out, PersianDate::from(date), time, Digits::Latin).ok()?;
+        Some(s)
+    })
+}
+
+/// Rewrite the jalali dates of `column` as gregorian ones, returns the number of converted cells
+///
+/// `in_fmt` is a jalali pattern (`%Y %m %d %H %M %S`, digits of any kind are accepted),
+/// `out_fmt` is a chrono pattern
+pub fn convert_csv_column_to_gregorian<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    column: &str,
+    in_fmt: &str,
+    out_fmt: &str,
+) -> Result<u64, CsvError> {
+    check_chrono_pattern(out_fmt)?;
+    let input: Vec<Item> = compile(in_fmt).map_err(|_| CsvError::InvalidPattern)?;
+    rewrite(reader, writer, column, |value| {
+        let (date, time) = parse_items(&input, value).ok()?;
+        let date = date.to_gregorian()?;
+        let mut s = String::new();
+        match time {
+            Some(time) => write!(s, "{}", date.and_time(time).format(out_fmt)).ok()?,
+            None => write!(s, "{}", date.format(out_fmt)).ok()?,
+        }
+        Some(s)
+    })
+}
+
+fn check_chrono_pattern(pattern: &str) -> Result<(), CsvError> {
+    StrftimeItems::new(pattern)
+        .parse()
+        .map(|_| ())
+        .map_err(|_| CsvError::InvalidPattern)
+}
+
+/// copy the csv, replacing the non-empty cells of `column` with `convert`
+fn rewrite<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    column: &str,
+    mut convert: impl FnMut(&str) -> Option<String>,
+) -> Result<u64, CsvError> {
+    let mut reader = ::csv::Reader::from_reader(reader);
+    let mut writer = ::csv::Writer::from_writer(writer);
+    let headers = reader.headers()?.clone();
+    let index = headers
+        .iter()
+        .position(|h| h == column)
+        .ok_or_else(|| CsvError::MissingColumn(column.to_string()))?;
+    writer.write_record(&headers)?;
+
+    let mut converted = 0;
+    let mut record = ::csv::StringRecord::new();
+    while reader.read_record(&mut record)? {
+        let value = record.get(index).unwrap_or_default();
+        if value.is_empty() {
+            writer.write_record(&record)?;
+            continue;
+        }
+        let Some(new) = convert(value) else {
+            return Err(CsvError::InvalidDate {
+                line: record.position().map_or(0, |p| p.line()),
+                value: value.to_string(),
+            });
+        };
+        let row = record
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| if i == index { new.as_str() } else { cell });
+        writer.write_record(row)?;
+        converted += 1;
+    }
+    writer.flush().map_err(::csv::Error::from)?;
+    Ok(converted)
+}
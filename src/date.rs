@@ -0,0 +1,287 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Weekday};
+use core::fmt;
+use core::num::NonZeroU8;
+use core::str::FromStr;
+
+use crate::resolve::local_in;
+use crate::{
+    calendar, is_valid_jalali, to_jalali, PersianDateTime, PersianError, PersianSpan, Resolve,
+};
+
+/// A date in the persian (jalali) calendar
+///
+/// month and day are never zero, so `Option<PersianDate>` takes no extra space
+/// ```rust
+///use chrono_persian::PersianDate;
+///
+///assert_eq!(size_of::<Option<PersianDate>>(), size_of::<PersianDate>());
+///assert_eq!(size_of::<PersianDate>(), 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersianDate {
+    year: i32,
+    month: NonZeroU8,
+    day: NonZeroU8,
+}
+
+impl PersianDate {
+    /// convert a gregorian calendar date, no timezone is involved
+    pub(crate) fn from_gregorian(date: &NaiveDate) -> Self {
+        let (year, month, day) = to_jalali(date);
+        Self::from_ymd_unchecked(year, month, day)
+    }
+
+    /// Make a date from its persian year, month and day, `None` if it's not a valid date
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///assert!(PersianDate::from_ymd_opt(1403, 12, 30).is_some());
+    ///assert!(PersianDate::from_ymd_opt(1404, 12, 30).is_none());
+    ///assert!(PersianDate::from_ymd_opt(1403, 7, 31).is_none());
+    /// ```
+    pub fn from_ymd_opt(year: i32, month: u32, day: u32) -> Option<Self> {
+        Self::try_from_ymd(year, month, day).ok()
+    }
+
+    /// Make a date like [`PersianDate::from_ymd_opt`], with the reason it failed
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianError};
+    ///
+    ///assert!(PersianDate::try_from_ymd(1403, 12, 30).is_ok());
+    ///assert_eq!(PersianDate::try_from_ymd(1404, 12, 30), Err(PersianError::InvalidJalaliDate { year: 1404, month: 12, day: 30 }));
+    ///assert_eq!(PersianDate::try_from_ymd(300000, 1, 1), Err(PersianError::OutOfRange));
+    /// ```
+    pub fn try_from_ymd(year: i32, month: u32, day: u32) -> Result<Self, PersianError> {
+        if !calendar::is_valid_ymd(year, month, day) {
+            return Err(PersianError::InvalidJalaliDate { year, month, day });
+        }
+        match is_valid_jalali(year, month, day) {
+            true => Ok(Self::from_ymd_unchecked(year, month, day)),
+            false => Err(PersianError::OutOfRange),
+        }
+    }
+
+    /// Make a date like [`PersianDate::from_ymd_opt`], but a day past the end of the month
+    /// becomes its last day and day 0 becomes the first, `None` if the month is invalid
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///assert_eq!(PersianDate::from_ymd_clamped(1404, 12, 30).unwrap().to_string(), "1404-12-29");
+    ///assert_eq!(PersianDate::from_ymd_clamped(1403, 7, 31).unwrap().to_string(), "1403-07-30");
+    ///assert_eq!(PersianDate::from_ymd_clamped(1403, 7, 0).unwrap().to_string(), "1403-07-01");
+    ///assert!(PersianDate::from_ymd_clamped(1403, 13, 1).is_none());
+    /// ```
+    pub fn from_ymd_clamped(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        let day = day.clamp(1, calendar::days_in_month(year, month));
+        Self::from_ymd_opt(year, month, day)
+    }
+
+    /// the caller guarantees the components form a valid jalali date
+    pub(crate) const fn from_ymd_unchecked(year: i32, month: u32, day: u32) -> Self {
+        let (Some(month), Some(day)) = (NonZeroU8::new(month as u8), NonZeroU8::new(day as u8))
+        else {
+            return Self {
+                year,
+                month: NonZeroU8::MIN,
+                day: NonZeroU8::MIN,
+            };
+        };
+        Self { year, month, day }
+    }
+
+    /// Get the equivalent gregorian date, `None` if it's out of chrono's range
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///assert_eq!(date.to_gregorian().unwrap().to_string(), "2024-11-10");
+    /// ```
+    pub fn to_gregorian(self) -> Option<NaiveDate> {
+        let (y, m, d) = calendar::jalali_to_gregorian(self.year, self.month(), self.day());
+        NaiveDate::from_ymd_opt(y, m, d)
+    }
+
+    /// Add a calendar span, such as [`crate::PersianMonths`] or [`crate::PersianDays`]
+    ///
+    /// when adding months the day is clamped to the last day of the resulting month.
+    /// returns `None` when the result is out of range
+    /// ```rust
+    ///use chrono::NaiveDate;
+    ///use chrono_persian::{PersianDate, PersianDays, PersianMonths};
+    ///
+    ///let date = PersianDate::from(NaiveDate::from_ymd_opt(2024, 9, 21).unwrap());
+    ///assert_eq!(date.to_string(), "1403-06-31");
+    ///
+    ///let a = date.checked_add(PersianMonths::new(1)).unwrap();
+    ///assert_eq!(a.to_string(), "1403-07-30");
+    ///
+    ///let b = date.checked_add(PersianDays::new(30)).unwrap();
+    ///assert_eq!(b.to_string(), "1403-07-30");
+    /// ```
+    pub fn checked_add<S: PersianSpan>(self, span: S) -> Option<Self> {
+        span.checked_add_to(self)
+    }
+
+    /// Subtract a calendar span, see [`PersianDate::checked_add`]
+    /// ```rust
+    ///use chrono::NaiveDate;
+    ///use chrono_persian::{PersianDate, PersianMonths};
+    ///
+    ///let date = PersianDate::from(NaiveDate::from_ymd_opt(2024, 5, 20).unwrap());
+    ///assert_eq!(date.to_string(), "1403-02-31");
+    ///
+    ///let a = date.checked_sub(PersianMonths::new(3)).unwrap();
+    ///assert_eq!(a.to_string(), "1402-11-30");
+    /// ```
+    pub fn checked_sub<S: PersianSpan>(self, span: S) -> Option<Self> {
+        span.checked_sub_from(self)
+    }
+
+    /// the day of the week, persian weeks start on saturday
+    /// ```rust
+    ///use chrono::Weekday;
+    ///use chrono_persian::PersianDate;
+    ///
+    ///assert_eq!(PersianDate::from_ymd_opt(1403, 8, 20).unwrap().weekday(), Weekday::Sun);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        let (y, m, d) = calendar::jalali_to_gregorian(self.year, self.month(), self.day());
+        match calendar::gregorian_weekday(y, m, d) {
+            0 => Weekday::Mon,
+            1 => Weekday::Tue,
+            2 => Weekday::Wed,
+            3 => Weekday::Thu,
+            4 => Weekday::Fri,
+            5 => Weekday::Sat,
+            _ => Weekday::Sun,
+        }
+    }
+
+    /// the persian year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// the persian month, starting from 1 (farvardin)
+    pub fn month(&self) -> u32 {
+        self.month.get() as u32
+    }
+
+    /// the day of the month, starting from 1
+    pub fn day(&self) -> u32 {
+        self.day.get() as u32
+    }
+
+    /// Make the instant at the given wall clock time in iran on this date, `None` if the time
+    /// is invalid, see [`PersianDate::and_time`]
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///let a = date.and_hms_opt(23, 7, 0).unwrap();
+    ///assert_eq!(a.to_string(), "1403-08-20 23:07:00 +03:30");
+    ///assert!(date.and_hms_opt(24, 0, 0).is_none());
+    /// ```
+    pub fn and_hms_opt(self, hour: u32, min: u32, sec: u32) -> Option<PersianDateTime> {
+        self.and_time(NaiveTime::from_hms_opt(hour, min, sec)?)
+    }
+
+    /// Make the instant at the given wall clock time in iran on this date, ambiguous and skipped
+    /// times are resolved with [`Resolve::Earliest`], see [`PersianDateTime::from_local`]
+    pub fn and_time(self, time: NaiveTime) -> Option<PersianDateTime> {
+        PersianDateTime::from_local(self, time, Resolve::Earliest)
+    }
+
+    /// Get midnight of this date in `zone` (the gregorian day of the date, in that zone),
+    /// `None` if midnight was skipped there
+    /// ```rust
+    ///use chrono::Utc;
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///assert_eq!(date.at_start_of_day_in(&Utc).unwrap().to_string(), "2024-11-10 00:00:00 UTC");
+    /// ```
+    pub fn at_start_of_day_in<Tz: TimeZone>(self, zone: &Tz) -> Option<DateTime<Tz>> {
+        let midnight = self.to_gregorian()?.and_time(NaiveTime::MIN);
+        zone.from_local_datetime(&midnight).earliest()
+    }
+
+    /// Get the first instant of this date in `zone` (the gregorian day of the date, in that
+    /// zone), which is after midnight when midnight was skipped there
+    /// ```rust
+    ///use chrono::{FixedOffset, Utc};
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///let tehran = FixedOffset::east_opt(3 * 3600 + 1800).unwrap();
+    ///assert_eq!(date.start_of_day_in(&tehran).unwrap().to_utc().to_string(), "2024-11-09 20:30:00 UTC");
+    ///assert_eq!(date.end_of_day_in(&Utc).unwrap().to_string(), "2024-11-10 23:59:59.999999999 UTC");
+    /// ```
+    pub fn start_of_day_in<Tz: TimeZone>(self, zone: &Tz) -> Option<DateTime<Tz>> {
+        let midnight = self.to_gregorian()?.and_time(NaiveTime::MIN);
+        local_in(zone, midnight, Resolve::Earliest)
+    }
+
+    /// Get the last instant (one nanosecond before the next day starts) of this date in `zone`,
+    /// see [`PersianDate::start_of_day_in`]
+    pub fn end_of_day_in<Tz: TimeZone>(self, zone: &Tz) -> Option<DateTime<Tz>> {
+        let next = self.to_gregorian()?.succ_opt()?;
+        PersianDate::from(next)
+            .start_of_day_in(zone)?
+            .checked_sub_signed(Duration::nanoseconds(1))
+    }
+}
+
+impl From<NaiveDate> for PersianDate {
+    fn from(value: NaiveDate) -> Self {
+        Self::from_gregorian(&value)
+    }
+}
+
+impl fmt::Display for PersianDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for PersianDate {
+    type Err = PersianError;
+
+    /// Parse `year/month/day`, the separator can also be `-`
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianError};
+    ///
+    ///let date = "1403/08/20".parse::<PersianDate>().unwrap();
+    ///assert_eq!(date, "1403-8-20".parse().unwrap());
+    ///
+    ///assert_eq!("1403/13/01".parse::<PersianDate>(), Err(PersianError::InvalidJalaliDate { year: 1403, month: 13, day: 1 }));
+    ///assert_eq!("20 aban".parse::<PersianDate>(), Err(PersianError::InvalidFormat));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, month, day) = split_ymd(s)?;
+        Self::from_ymd_opt(year, month, day).ok_or(PersianError::InvalidJalaliDate {
+            year,
+            month,
+            day,
+        })
+    }
+}
+
+/// split `year/month/day` (or `year-month-day`) into its components without validating them
+pub(crate) fn split_ymd(s: &str) -> Result<(i32, u32, u32), PersianError> {
+    let mut parts = s.trim().split(['/', '-']).map(|p| {
+        if p.is_empty() || !p.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(PersianError::InvalidFormat);
+        }
+        p.parse::<i32>().map_err(|_| PersianError::InvalidFormat)
+    });
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(PersianError::InvalidFormat);
+    };
+    Ok((year?, month? as u32, day? as u32))
+}
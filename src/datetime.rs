@@ -0,0 +1,488 @@
+use alloc::{format, string::String, vec::Vec};
+use chrono::{
+    DateTime, Duration, FixedOffset, NaiveTime, Offset, SecondsFormat, TimeZone, Timelike, Utc,
+    Weekday,
+};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::{to_tehran, PersianDate, PersianError, Resolve};
+
+/// A persian (jalali) date and time, backed by the instant it refers to
+///
+/// equality, ordering and arithmetic all work on the underlying instant.
+/// the wall clock is iran's unless another offset is picked with
+/// [`PersianDateTime::with_offset`]
+#[derive(Debug, Clone, Copy)]
+pub struct PersianDateTime {
+    date: PersianDate,
+    datetime: DateTime<FixedOffset>,
+    /// the offset was picked by the user, arithmetic keeps it instead of following iran's
+    pinned: bool,
+}
+
+impl PersianDateTime {
+    /// the wall clock `datetime` shows, in the persian calendar
+    pub(crate) fn from_fixed(datetime: DateTime<FixedOffset>) -> Self {
+        Self {
+            date: PersianDate::from_gregorian(&datetime.date_naive()),
+            datetime,
+            pinned: false,
+        }
+    }
+
+    /// the caller guarantees `date` is the persian date of `datetime`'s wall clock
+    pub(crate) fn from_parts(date: PersianDate, datetime: DateTime<FixedOffset>) -> Self {
+        Self {
+            date,
+            datetime,
+            pinned: false,
+        }
+    }
+
+    /// Show the same instant on a wall clock at `offset`, the offset is kept through
+    /// arithmetic and serialization
+    /// ```rust
+    ///use chrono::{DateTime, Duration, FixedOffset, Utc};
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let kabul = FixedOffset::east_opt(4 * 3600 + 1800).unwrap();
+    ///let a = PersianDateTime::from(utc).with_offset(kabul);
+    ///assert_eq!(a.to_string(), "1403-08-20 03:08:28 +04:30");
+    ///assert_eq!(a, PersianDateTime::from(utc));
+    ///assert_eq!(a.offset(), kabul);
+    ///
+    ///let b = a + Duration::hours(21);
+    ///assert_eq!(b.to_string(), "1403-08-21 00:08:28 +04:30");
+    /// ```
+    pub fn with_offset(self, offset: FixedOffset) -> Self {
+        Self {
+            pinned: true,
+            ..Self::from_fixed(self.datetime.with_timezone(&offset))
+        }
+    }
+
+    /// the same instant on the wall clock of `tz` (any chrono time zone, such as a
+    /// `chrono_tz::Tz`), like [`PersianDateTime::with_offset`] with the offset `tz` has at
+    /// the instant
+    ///
+    /// the offset is kept as it is by arithmetic, it doesn't follow daylight saving time of `tz`
+    /// ```rust
+    ///use chrono::{DateTime, FixedOffset, Utc};
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let a = PersianDateTime::from(utc);
+    ///assert_eq!(a.in_zone(&Utc).to_string(), "1403-08-19 22:38:28 +00:00");
+    ///
+    ///let los_angeles = FixedOffset::west_opt(8 * 3600).unwrap();
+    ///let b = a.in_zone(&los_angeles);
+    ///assert_eq!((b.day(), b.hour()), (19, 14));
+    ///assert_eq!(b, a);
+    /// ```
+    pub fn in_zone<Tz: TimeZone>(&self, tz: &Tz) -> Self {
+        self.with_offset(self.datetime.with_timezone(tz).offset().fix())
+    }
+
+    /// Make the instant a wall clock in iran shows, `None` if the date or time is invalid
+    /// ```rust
+    ///use chrono::Weekday;
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let a = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 2, 8, 28).unwrap();
+    ///assert_eq!(a.to_utc().to_string(), "2024-11-09 22:38:28 UTC");
+    ///assert_eq!((a.year(), a.month(), a.day()), (1403, 8, 20));
+    ///assert_eq!((a.hour(), a.minute(), a.second()), (2, 8, 28));
+    ///assert_eq!(a.weekday(), Weekday::Sun);
+    ///
+    ///assert!(PersianDateTime::from_ymd_hms_opt(1404, 12, 30, 0, 0, 0).is_none());
+    /// ```
+    pub fn from_ymd_hms_opt(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> Option<Self> {
+        Self::try_from_ymd_hms(year, month, day, hour, min, sec).ok()
+    }
+
+    /// Make the instant like [`PersianDateTime::from_ymd_hms_opt`], with the reason it failed
+    /// ```rust
+    ///use chrono_persian::{PersianDateTime, PersianError};
+    ///
+    ///assert!(PersianDateTime::try_from_ymd_hms(1403, 8, 20, 2, 8, 28).is_ok());
+    ///assert_eq!(
+    ///    PersianDateTime::try_from_ymd_hms(1403, 8, 20, 24, 0, 0),
+    ///    Err(PersianError::InvalidTime { hour: 24, minute: 0, second: 0 })
+    ///);
+    /// ```
+    pub fn try_from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> Result<Self, PersianError> {
+        let date = PersianDate::try_from_ymd(year, month, day)?;
+        let time = NaiveTime::from_hms_opt(hour, min, sec).ok_or(PersianError::InvalidTime {
+            hour,
+            minute: min,
+            second: sec,
+        })?;
+        Self::try_from_local(date, time, Resolve::Earliest)
+    }
+
+    /// the persian date of the wall clock, in iran unless picked with
+    /// [`PersianDateTime::with_offset`]
+    pub fn date(&self) -> PersianDate {
+        self.date
+    }
+
+    /// the wall clock time, in iran unless picked with [`PersianDateTime::with_offset`]
+    pub fn time(&self) -> NaiveTime {
+        self.datetime.time()
+    }
+
+    /// the persian year
+    pub fn year(&self) -> i32 {
+        self.date.year()
+    }
+
+    /// the persian month, starting from 1 (farvardin)
+    pub fn month(&self) -> u32 {
+        self.date.month()
+    }
+
+    /// the day of the month, starting from 1
+    pub fn day(&self) -> u32 {
+        self.date.day()
+    }
+
+    /// the day of the week
+    pub fn weekday(&self) -> Weekday {
+        self.date.weekday()
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.datetime.hour()
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.datetime.minute()
+    }
+
+    pub fn second(&self) -> u32 {
+        self.datetime.second()
+    }
+
+    /// the nanoseconds since the last whole second, over a billion during a leap second
+    pub fn nanosecond(&self) -> u32 {
+        self.datetime.nanosecond()
+    }
+
+    /// the offset of the wall clock from utc
+    pub fn offset(&self) -> FixedOffset {
+        *self.datetime.offset()
+    }
+
+    /// the instant with the offset of the wall clock, the date is gregorian
+    pub fn to_fixed_offset(&self) -> DateTime<FixedOffset> {
+        self.datetime
+    }
+
+    /// the instant in utc
+    pub fn to_utc(&self) -> DateTime<Utc> {
+        self.datetime.to_utc()
+    }
+
+    /// the seconds since the unix epoch
+    /// ```rust
+    ///use chrono_persian::{persian_from_unix_timestamp, PersianDateTime};
+    ///
+    ///let a = persian_from_unix_timestamp(1731191908).unwrap();
+    ///assert_eq!(a.to_string(), "1403-08-20 02:08:28 +03:30");
+    ///assert_eq!(a.unix_timestamp(), 1731191908);
+    ///assert!(persian_from_unix_timestamp(i64::MAX).is_none());
+    /// ```
+    pub fn unix_timestamp(&self) -> i64 {
+        self.datetime.timestamp()
+    }
+
+    /// Write the datetime like chrono's `to_rfc3339`, with the persian date, e.g.
+    /// `1403-08-20T02:08:28.500+03:30`
+    pub fn to_rfc3339(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::AutoSi, false)
+    }
+
+    /// Write the datetime like chrono's `to_rfc3339_opts`, with the persian date
+    ///
+    /// `secform` picks the digits of the second, and `use_z` writes a zero offset as `Z`
+    /// ```rust
+    ///use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let utc = "2024-11-09 22:38:28.5 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let a = PersianDateTime::from(utc);
+    ///assert_eq!(a.to_rfc3339(), "1403-08-20T02:08:28.500+03:30");
+    ///assert_eq!(a.to_rfc3339_opts(SecondsFormat::Secs, false), "1403-08-20T02:08:28+03:30");
+    ///
+    ///let b = a.with_offset(FixedOffset::east_opt(0).unwrap());
+    ///assert_eq!(b.to_rfc3339_opts(SecondsFormat::Millis, true), "1403-08-19T22:38:28.500Z");
+    ///assert_eq!(b.to_rfc3339_opts(SecondsFormat::Micros, false), "1403-08-19T22:38:28.500000+00:00");
+    /// ```
+    pub fn to_rfc3339_opts(&self, secform: SecondsFormat, use_z: bool) -> String {
+        // chrono's time and offset, after the persian date
+        let gregorian = self.datetime.to_rfc3339_opts(secform, use_z);
+        let time = &gregorian[gregorian.find('T').unwrap_or(0)..];
+        format!("{}{time}", self.date)
+    }
+
+    /// Parse a datetime written by [`PersianDateTime::to_rfc3339`],
+    /// `year-month-dayThh:mm:ss[.fff](+hh:mm|Z)`
+    ///
+    /// an offset other than iran's at that instant is kept like [`PersianDateTime::with_offset`]
+    /// does, iran's offset gives a value in iran's time like [`PersianDateTime::from`]
+    /// ```rust
+    ///use chrono_persian::{PersianDateTime, PersianError};
+    ///
+    ///let a = PersianDateTime::parse_from_rfc3339("1403-08-20T02:08:28.500+03:30").unwrap();
+    ///assert_eq!(a.to_rfc3339(), "1403-08-20T02:08:28.500+03:30");
+    ///let b = PersianDateTime::parse_from_rfc3339("1403-08-19T22:38:28Z").unwrap();
+    ///assert_eq!(b.to_rfc3339(), "1403-08-19T22:38:28+00:00");
+    ///
+    ///let err = PersianDateTime::parse_from_rfc3339("1403-08-20 02:08:28");
+    ///assert_eq!(err, Err(PersianError::InvalidFormat));
+    /// ```
+    pub fn parse_from_rfc3339(s: &str) -> Result<Self, PersianError> {
+        parse_rfc3339(s).ok_or(PersianError::InvalidFormat)
+    }
+
+    /// Check converting to a gregorian datetime with [`PersianDateTime::to_fixed_offset`] and
+    /// back with the public conversions gives the same value, with the same offset, wall clock
+    /// and sub-second part, and that the wall clock is the gregorian one converted on its own
+    ///
+    /// a value with a picked offset comes back through `ToPersian`, which keeps the offset,
+    /// and one in iran's time through `PersianDateTime::from`
+    /// ```rust
+    ///use chrono::{DateTime, FixedOffset, Utc};
+    ///use chrono_persian::{PersianDateTime, ToPersian};
+    ///
+    ///let utc = "2024-11-09 22:38:28.123456789 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let a = PersianDateTime::from(utc);
+    ///assert!(a.round_trip_check());
+    ///
+    ///let new_york = utc.with_timezone(&FixedOffset::west_opt(5 * 3600).unwrap());
+    ///let b = new_york.to_persian().unwrap();
+    ///assert!(b.round_trip_check());
+    ///assert_eq!(b.to_fixed_offset(), new_york);
+    ///assert_eq!(b.to_string(), "1403-08-19 17:38:28.123456789 -05:00");
+    /// ```
+    pub fn round_trip_check(&self) -> bool {
+        let gregorian = self.to_fixed_offset();
+        let back = match self.pinned {
+            true => crate::ToPersian::to_persian(&gregorian),
+            false => Some(PersianDateTime::from(gregorian.to_utc())),
+        };
+        let same = back.is_some_and(|back| {
+            back == *self
+                && back.offset() == self.offset()
+                && back.date() == self.date()
+                && back.time() == self.time()
+        });
+        same && self.date() == PersianDate::from(gregorian.date_naive())
+            && self.time() == gregorian.time()
+    }
+
+    /// Add a duration to the instant, returns `None` when the result is out of range
+    /// ```rust
+    ///use chrono::{DateTime, Duration, Utc};
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let start = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let a = PersianDateTime::from(start);
+    ///assert_eq!(a.to_string(), "1403-08-20 02:08:28 +03:30");
+    ///
+    ///let expiry = a.checked_add_signed(Duration::days(11)).unwrap();
+    ///assert_eq!(expiry.to_string(), "1403-09-01 02:08:28 +03:30");
+    ///assert_eq!(expiry - Duration::days(11), a);
+    /// ```
+    pub fn checked_add_signed(self, rhs: Duration) -> Option<Self> {
+        Some(self.rezone(self.datetime.checked_add_signed(rhs)?))
+    }
+
+    /// Subtract a duration from the instant, returns `None` when the result is out of range
+    pub fn checked_sub_signed(self, rhs: Duration) -> Option<Self> {
+        Some(self.rezone(self.datetime.checked_sub_signed(rhs)?))
+    }
+
+    /// an instant on the same kind of wall clock as `self`, iran's may have another offset by then
+    fn rezone(self, datetime: DateTime<FixedOffset>) -> Self {
+        match self.pinned {
+            true => Self::from(datetime).with_offset(self.offset()),
+            false => Self::from(datetime),
+        }
+    }
+
+    /// Get the elapsed time from `rhs` to `self`, negative if `rhs` is later
+    /// ```rust
+    ///use chrono::{DateTime, Duration, Utc};
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let start = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let a = PersianDateTime::from(start);
+    ///let b = PersianDateTime::from(start + Duration::minutes(90));
+    ///assert_eq!(b.signed_duration_since(a), Duration::minutes(90));
+    ///assert_eq!(a - b, Duration::minutes(-90));
+    /// ```
+    pub fn signed_duration_since(&self, rhs: PersianDateTime) -> Duration {
+        self.datetime.signed_duration_since(rhs.datetime)
+    }
+}
+
+fn parse_rfc3339(s: &str) -> Option<PersianDateTime> {
+    let (date, rest) = s.split_once('T')?;
+    let date = date.parse::<PersianDate>().ok()?;
+    let (time, offset) = match rest.strip_suffix('Z') {
+        Some(time) => (time, FixedOffset::east_opt(0)?),
+        None => {
+            let at = rest.rfind(['+', '-'])?;
+            (&rest[..at], rest[at..].parse().ok()?)
+        }
+    };
+    let time = NaiveTime::parse_from_str(time, "%H:%M:%S%.f").ok()?;
+    let local = date.to_gregorian()?.and_time(time);
+    let datetime = DateTime::<FixedOffset>::from_naive_utc_and_offset(
+        local.checked_sub_offset(offset)?,
+        offset,
+    );
+    // iran's own offset isn't a choice of the writer, the value keeps following iran's time
+    let tehran = PersianDateTime::from(datetime);
+    match tehran.offset() == offset {
+        true => Some(tehran),
+        false => Some(tehran.with_offset(offset)),
+    }
+}
+
+/// Convert seconds since the unix epoch to the persian date and time in iran, `None` if out of
+/// range
+pub fn persian_from_unix_timestamp(secs: i64) -> Option<PersianDateTime> {
+    Some(PersianDateTime::from(DateTime::from_timestamp(secs, 0)?))
+}
+
+/// the instant on the wall clock of each zone, in the order of `zones`, see
+/// [`PersianDateTime::in_zone`]
+/// ```rust
+///use chrono::{DateTime, FixedOffset, Utc};
+///use chrono_persian::render_in_zones;
+///
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let zones = [3600, -5 * 3600, 10 * 3600].map(|s| FixedOffset::east_opt(s).unwrap());
+///let clocks: Vec<_> = render_in_zones(utc, &zones).iter().map(|p| p.to_string()).collect();
+///assert_eq!(
+///    clocks,
+///    [
+///        "1403-08-19 23:38:28 +01:00",
+///        "1403-08-19 17:38:28 -05:00",
+///        "1403-08-20 08:38:28 +10:00",
+///    ]
+///);
+/// ```
+pub fn render_in_zones<Tz: TimeZone>(instant: DateTime<Utc>, zones: &[Tz]) -> Vec<PersianDateTime> {
+    let value = PersianDateTime::from(instant);
+    zones.iter().map(|tz| value.in_zone(tz)).collect()
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for PersianDateTime {
+    /// Convert an instant to the persian date and time in iran
+    fn from(value: DateTime<Tz>) -> Self {
+        Self::from_fixed(to_tehran(&value))
+    }
+}
+
+impl From<PersianDateTime> for DateTime<FixedOffset> {
+    fn from(value: PersianDateTime) -> Self {
+        value.datetime
+    }
+}
+
+impl Sub for PersianDateTime {
+    type Output = Duration;
+
+    fn sub(self, rhs: PersianDateTime) -> Duration {
+        self.signed_duration_since(rhs)
+    }
+}
+
+impl Add<Duration> for PersianDateTime {
+    type Output = PersianDateTime;
+
+    fn add(self, rhs: Duration) -> PersianDateTime {
+        self.checked_add_signed(rhs)
+            .expect("`PersianDateTime + Duration` overflowed")
+    }
+}
+
+impl AddAssign<Duration> for PersianDateTime {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for PersianDateTime {
+    type Output = PersianDateTime;
+
+    fn sub(self, rhs: Duration) -> PersianDateTime {
+        self.checked_sub_signed(rhs)
+            .expect("`PersianDateTime - Duration` overflowed")
+    }
+}
+
+impl SubAssign<Duration> for PersianDateTime {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl PartialEq for PersianDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.datetime == other.datetime
+    }
+}
+
+impl Eq for PersianDateTime {}
+
+impl PartialOrd for PersianDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PersianDateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.datetime.cmp(&other.datetime)
+    }
+}
+
+impl Hash for PersianDateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.datetime.hash(state)
+    }
+}
+
+impl fmt::Display for PersianDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.date,
+            self.time(),
+            self.datetime.offset()
+        )
+    }
+}
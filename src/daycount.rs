@@ -0,0 +1,64 @@
+use crate::{calendar, PersianDate};
+
+/// How the time between two dates is measured as a fraction of a persian year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayCountConvention {
+    /// actual days divided by 365
+    Actual365,
+    /// actual days, each day weighted by the length (365 or 366) of the persian year it's in
+    ActualActual,
+    /// every month counts as 30 days and the year as 360, day 31 is treated as day 30
+    Thirty360Jalali,
+}
+
+/// Get the time from `a` to `b` as a fraction of a persian year, negative if `b` is before `a`
+///
+/// returns `None` when either date can't be converted
+/// ```rust
+///use chrono_persian::{day_count, DayCountConvention, PersianDate};
+///
+///let a = PersianDate::from_ymd_opt(1403, 1, 1).unwrap();
+///let b = PersianDate::from_ymd_opt(1403, 7, 1).unwrap();
+///
+///assert_eq!(day_count(a, b, DayCountConvention::Actual365), Some(186.0 / 365.0));
+///assert_eq!(day_count(a, b, DayCountConvention::ActualActual), Some(186.0 / 366.0));
+///assert_eq!(day_count(a, b, DayCountConvention::Thirty360Jalali), Some(0.5));
+///assert_eq!(day_count(b, a, DayCountConvention::Thirty360Jalali), Some(-0.5));
+/// ```
+pub fn day_count(a: PersianDate, b: PersianDate, convention: DayCountConvention) -> Option<f64> {
+    if b < a {
+        return day_count(b, a, convention).map(|f| -f);
+    }
+    match convention {
+        DayCountConvention::Actual365 => Some(actual_days(a, b)? as f64 / 365.0),
+        DayCountConvention::ActualActual => {
+            let mut fraction = 0.0;
+            let mut start = a;
+            for year in a.year()..=b.year() {
+                let end = if year == b.year() {
+                    b
+                } else {
+                    PersianDate::from_ymd_opt(year + 1, 1, 1)?
+                };
+                let len = if calendar::is_leap_year(year) {
+                    366.0
+                } else {
+                    365.0
+                };
+                fraction += actual_days(start, end)? as f64 / len;
+                start = end;
+            }
+            Some(fraction)
+        }
+        DayCountConvention::Thirty360Jalali => {
+            let days = 360 * (b.year() - a.year()) as i64
+                + 30 * (b.month() as i64 - a.month() as i64)
+                + (b.day().min(30) as i64 - a.day().min(30) as i64);
+            Some(days as f64 / 360.0)
+        }
+    }
+}
+
+fn actual_days(a: PersianDate, b: PersianDate) -> Option<i64> {
+    Some((b.to_gregorian()? - a.to_gregorian()?).num_days())
+}
@@ -0,0 +1,268 @@
+use core::iter::FusedIterator;
+use core::ops::{Range, RangeInclusive};
+
+use crate::{calendar, PersianDate, PersianDateRange, PersianMonthOfYear};
+
+impl PersianDate {
+    /// the next day, `None` when out of range
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 12, 30).unwrap();
+    ///assert_eq!(date.succ_opt().unwrap().to_string(), "1404-01-01");
+    ///assert_eq!(date.pred_opt().unwrap().to_string(), "1403-12-29");
+    /// ```
+    pub fn succ_opt(&self) -> Option<Self> {
+        let (y, m, d) = (self.year(), self.month(), self.day());
+        match (d < calendar::days_in_month(y, m), m) {
+            (true, _) => Self::from_ymd_opt(y, m, d + 1),
+            (false, 12) => Self::from_ymd_opt(y.checked_add(1)?, 1, 1),
+            (false, _) => Self::from_ymd_opt(y, m + 1, 1),
+        }
+    }
+
+    /// the previous day, `None` when out of range
+    pub fn pred_opt(&self) -> Option<Self> {
+        let (y, m, d) = (self.year(), self.month(), self.day());
+        match (d, m) {
+            (2.., _) => Self::from_ymd_opt(y, m, d - 1),
+            (_, 1) => {
+                let y = y.checked_sub(1)?;
+                Self::from_ymd_opt(y, 12, calendar::days_in_month(y, 12))
+            }
+            (_, _) => Self::from_ymd_opt(y, m - 1, calendar::days_in_month(y, m - 1)),
+        }
+    }
+
+    /// the first day of the date's month
+    pub fn first_day_of_month(&self) -> Self {
+        Self::from_ymd_unchecked(self.year(), self.month(), 1)
+    }
+
+    /// the last day of the date's month
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1404, 12, 10).unwrap();
+    ///assert_eq!(date.first_day_of_month().to_string(), "1404-12-01");
+    ///assert_eq!(date.last_day_of_month().to_string(), "1404-12-29");
+    /// ```
+    pub fn last_day_of_month(&self) -> Self {
+        let days = calendar::days_in_month(self.year(), self.month());
+        Self::from_ymd_unchecked(self.year(), self.month(), days)
+    }
+
+    /// Iterate over the days from this date on, like chrono's `NaiveDate::iter_days`
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 6, 30).unwrap();
+    ///let days: Vec<_> = date.iter_days().take(3).map(|d| d.to_string()).collect();
+    ///assert_eq!(days, ["1403-06-30", "1403-06-31", "1403-07-01"]);
+    /// ```
+    pub fn iter_days(&self) -> PersianDateDaysIterator {
+        PersianDateDaysIterator { next: Some(*self) }
+    }
+
+    /// Iterate over the months from the date's month on
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 11, 15).unwrap();
+    ///let lengths: Vec<_> = date.iter_months().take(3).map(|m| (m.month(), m.num_days())).collect();
+    ///assert_eq!(lengths, [(11, 30), (12, 30), (1, 31)]);
+    /// ```
+    pub fn iter_months(&self) -> PersianMonthsIterator {
+        PersianMonthsIterator {
+            next: Some(self.month_of_year()),
+        }
+    }
+}
+
+/// Iterator over the days from a date on, see [`PersianDate::iter_days`]
+#[derive(Debug, Clone)]
+pub struct PersianDateDaysIterator {
+    next: Option<PersianDate>,
+}
+
+impl Iterator for PersianDateDaysIterator {
+    type Item = PersianDate;
+
+    fn next(&mut self) -> Option<PersianDate> {
+        let day = self.next?;
+        self.next = day.succ_opt();
+        Some(day)
+    }
+}
+
+impl FusedIterator for PersianDateDaysIterator {}
+
+/// Iterator over the months from a date's month on, see [`PersianDate::iter_months`]
+#[derive(Debug, Clone)]
+pub struct PersianMonthsIterator {
+    next: Option<PersianMonthOfYear>,
+}
+
+impl Iterator for PersianMonthsIterator {
+    type Item = PersianMonthOfYear;
+
+    fn next(&mut self) -> Option<PersianMonthOfYear> {
+        let month = self.next?;
+        self.next = month.last_day().succ_opt().map(|d| d.month_of_year());
+        Some(month)
+    }
+}
+
+impl FusedIterator for PersianMonthsIterator {}
+
+impl PersianDateRange {
+    /// the days of the range, in order
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianDateRange};
+    ///
+    ///let start = PersianDate::from_ymd_opt(1403, 7, 1).unwrap();
+    ///let mehr = PersianDateRange::new(start, start.last_day_of_month()).unwrap();
+    ///assert_eq!(mehr.days().len(), 30);
+    ///assert_eq!(mehr.days().next_back().unwrap().to_string(), "1403-07-30");
+    ///
+    ///for day in mehr {
+    ///    assert_eq!(day.month(), 7);
+    ///}
+    /// ```
+    pub fn days(&self) -> RangeDays {
+        RangeDays {
+            front: self.start(),
+            back: self.end(),
+            len: (self.end() - self.start()).num_days() as usize + 1,
+        }
+    }
+}
+
+impl IntoIterator for PersianDateRange {
+    type Item = PersianDate;
+    type IntoIter = RangeDays;
+
+    fn into_iter(self) -> RangeDays {
+        self.days()
+    }
+}
+
+/// Iterator over the days of a [`PersianDateRange`]
+#[derive(Debug, Clone)]
+pub struct RangeDays {
+    front: PersianDate,
+    back: PersianDate,
+    /// days left, the ends are only meaningful while it's not 0
+    len: usize,
+}
+
+impl Iterator for RangeDays {
+    type Item = PersianDate;
+
+    fn next(&mut self) -> Option<PersianDate> {
+        if self.len == 0 {
+            return None;
+        }
+        let day = self.front;
+        self.len -= 1;
+        if self.len > 0 {
+            self.front = day.succ_opt()?;
+        }
+        Some(day)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl DoubleEndedIterator for RangeDays {
+    fn next_back(&mut self) -> Option<PersianDate> {
+        if self.len == 0 {
+            return None;
+        }
+        let day = self.back;
+        self.len -= 1;
+        if self.len > 0 {
+            self.back = day.pred_opt()?;
+        }
+        Some(day)
+    }
+}
+
+impl ExactSizeIterator for RangeDays {}
+
+impl FusedIterator for RangeDays {}
+
+/// Iterate over a `Range` of dates on stable rust, such as `(start..end).days()`
+///
+/// with the `nightly` feature dates implement the unstable `Step` trait so the range itself
+/// is an iterator
+/// ```rust
+///use chrono_persian::{PersianDate, PersianRangeExt};
+///
+///let start = PersianDate::from_ymd_opt(1403, 12, 29).unwrap();
+///let end = PersianDate::from_ymd_opt(1404, 1, 2).unwrap();
+///let days: Vec<_> = (start..end).days().map(|d| d.to_string()).collect();
+///assert_eq!(days, ["1403-12-29", "1403-12-30", "1404-01-01"]);
+///assert_eq!((start..=end).days().len(), 4);
+///assert_eq!((end..start).days().len(), 0);
+/// ```
+pub trait PersianRangeExt {
+    fn days(self) -> RangeDays;
+}
+
+impl PersianRangeExt for Range<PersianDate> {
+    fn days(self) -> RangeDays {
+        match self.end.pred_opt() {
+            Some(last) => (self.start..=last).days(),
+            None => RangeDays {
+                front: self.start,
+                back: self.start,
+                len: 0,
+            },
+        }
+    }
+}
+
+impl PersianRangeExt for RangeInclusive<PersianDate> {
+    fn days(self) -> RangeDays {
+        let (start, end) = self.into_inner();
+        match PersianDateRange::new(start, end) {
+            Some(range) => range.days(),
+            None => RangeDays {
+                front: start,
+                back: start,
+                len: 0,
+            },
+        }
+    }
+}
+
+/// the days between dates, requires the `nightly` feature
+/// ```rust
+///#![feature(step_trait)]
+///use chrono_persian::PersianDate;
+///
+///let start = PersianDate::from_ymd_opt(1403, 6, 30).unwrap();
+///let end = PersianDate::from_ymd_opt(1403, 7, 2).unwrap();
+///let days: Vec<_> = (start..end).map(|d| d.to_string()).collect();
+///assert_eq!(days, ["1403-06-30", "1403-06-31", "1403-07-01"]);
+/// ```
+#[cfg(feature = "nightly")]
+impl core::iter::Step for PersianDate {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        match usize::try_from((*end - *start).num_days()) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (0, None),
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        start.checked_add_days(i64::try_from(count).ok()?)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        start.checked_add_days(-i64::try_from(count).ok()?)
+    }
+}
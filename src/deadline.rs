@@ -0,0 +1,163 @@
+use alloc::vec::Vec;
+use chrono::{DateTime, Duration, FixedOffset, TimeZone};
+
+use crate::PersianUnit;
+
+/// Get the first instant of the persian `unit` containing `instant`, in tehran time
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::{start_of_persian, PersianUnit};
+///
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let start = start_of_persian(&utc, PersianUnit::Quarter).unwrap();
+///assert_eq!(start.to_string(), "2024-09-22 00:00:00 +03:30");
+/// ```
+pub fn start_of_persian<Tz: TimeZone>(
+    instant: &DateTime<Tz>,
+    unit: PersianUnit,
+) -> Option<DateTime<FixedOffset>> {
+    Some(unit.bounds(instant)?.0)
+}
+
+/// Get the last instant (one nanosecond before the next one starts) of the persian `unit`
+/// containing `instant`, in tehran time
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::{end_of_persian, PeriodScheme, PersianUnit};
+///
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let end = end_of_persian(&utc, PersianUnit::Year).unwrap();
+///assert_eq!(end.to_string(), "2025-03-20 23:59:59.999999999 +03:30");
+///
+/// // the fiscal year starting at mehr ends with shahrivar
+///let fiscal = PersianUnit::Scheme(PeriodScheme::fiscal_year(7));
+///let end = end_of_persian(&utc, fiscal).unwrap();
+///assert_eq!(end.to_string(), "2025-09-22 23:59:59.999999999 +03:30");
+/// ```
+pub fn end_of_persian<Tz: TimeZone>(
+    instant: &DateTime<Tz>,
+    unit: PersianUnit,
+) -> Option<DateTime<FixedOffset>> {
+    unit.bounds(instant)?
+        .1
+        .checked_sub_signed(Duration::nanoseconds(1))
+}
+
+/// Get the last instant of the persian day containing `instant`, see [`end_of_persian`]
+pub fn end_of_persian_day<Tz: TimeZone>(instant: &DateTime<Tz>) -> Option<DateTime<FixedOffset>> {
+    end_of_persian(instant, PersianUnit::Day)
+}
+
+/// Get the last instant of the persian week (friday night) containing `instant`,
+/// see [`end_of_persian`]
+pub fn end_of_persian_week<Tz: TimeZone>(instant: &DateTime<Tz>) -> Option<DateTime<FixedOffset>> {
+    end_of_persian(instant, PersianUnit::Week)
+}
+
+/// Get the last instant of the persian month containing `instant`, see [`end_of_persian`]
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::end_of_persian_month;
+///
+/// // 1403-08-20 in tehran, aban has 30 days
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let end = end_of_persian_month(&utc).unwrap();
+///assert_eq!(end.to_string(), "2024-11-20 23:59:59.999999999 +03:30");
+/// ```
+pub fn end_of_persian_month<Tz: TimeZone>(instant: &DateTime<Tz>) -> Option<DateTime<FixedOffset>> {
+    end_of_persian(instant, PersianUnit::Month)
+}
+
+/// Get the last instant of the persian quarter containing `instant`, see [`end_of_persian`]
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::end_of_persian_quarter;
+///
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let end = end_of_persian_quarter(&utc).unwrap();
+///assert_eq!(end.to_string(), "2024-12-20 23:59:59.999999999 +03:30");
+/// ```
+pub fn end_of_persian_quarter<Tz: TimeZone>(
+    instant: &DateTime<Tz>,
+) -> Option<DateTime<FixedOffset>> {
+    end_of_persian(instant, PersianUnit::Quarter)
+}
+
+/// Get the last instant of the persian year containing `instant`, see [`end_of_persian`]
+pub fn end_of_persian_year<Tz: TimeZone>(instant: &DateTime<Tz>) -> Option<DateTime<FixedOffset>> {
+    end_of_persian(instant, PersianUnit::Year)
+}
+
+/// Get every persian month start (in tehran time) in `a..b`, in order
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::month_boundaries;
+///
+///let a = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+/// // bahman 1st in tehran, the end is excluded
+///let b = "2025-01-19 20:30:00 UTC".parse::<DateTime<Utc>>().unwrap();
+///let starts: Vec<_> = month_boundaries(&a, &b).iter().map(|s| s.to_string()).collect();
+///assert_eq!(starts, ["2024-11-21 00:00:00 +03:30", "2024-12-21 00:00:00 +03:30"]);
+/// ```
+pub fn month_boundaries<Tz: TimeZone>(
+    a: &DateTime<Tz>,
+    b: &DateTime<Tz>,
+) -> Vec<DateTime<FixedOffset>> {
+    let mut starts = Vec::new();
+    let Some((first, next)) = PersianUnit::Month.bounds(a) else {
+        return starts;
+    };
+    let mut start = if first == *a { first } else { next };
+    while start < *b {
+        starts.push(start);
+        match PersianUnit::Month.bounds(&start) {
+            Some((_, next)) => start = next,
+            None => break,
+        }
+    }
+    starts
+}
+
+/// Get the edges of the persian `unit` buckets covering `start..end` (in tehran time), for
+/// downsampling a time series
+///
+/// the first edge is the start of the bucket containing `start` and the last one is the
+/// first edge at or after `end`, so `n` buckets have `n + 1` edges
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::{resample_boundaries, PersianUnit};
+///
+///let start = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let end = "2025-01-01 00:00:00 UTC".parse::<DateTime<Utc>>().unwrap();
+///let edges: Vec<_> = resample_boundaries(&start, &end, PersianUnit::Quarter)
+///    .iter()
+///    .map(|e| e.to_string())
+///    .collect();
+///assert_eq!(edges, ["2024-09-22 00:00:00 +03:30", "2024-12-21 00:00:00 +03:30", "2025-03-21 00:00:00 +03:30"]);
+///
+///assert!(resample_boundaries(&start, &start, PersianUnit::Week).is_empty());
+/// ```
+pub fn resample_boundaries<Tz: TimeZone>(
+    start: &DateTime<Tz>,
+    end: &DateTime<Tz>,
+    unit: PersianUnit,
+) -> Vec<DateTime<FixedOffset>> {
+    let mut edges = Vec::new();
+    if start >= end {
+        return edges;
+    }
+    let Some((mut edge, _)) = unit.bounds(start) else {
+        return edges;
+    };
+    loop {
+        edges.push(edge);
+        if edge >= *end {
+            break;
+        }
+        match unit.bounds(&edge) {
+            Some((_, next)) => edge = next,
+            None => break,
+        }
+    }
+    edges
+}
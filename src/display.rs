@@ -0,0 +1,125 @@
+//! Runtime of the [`PersianDisplay`](crate::PersianDisplay) derive (requires the `derive` feature)
+//!
+//! the derived `Display` shows the struct like `Debug` does, but with its date fields in jalali.
+//! fields of chrono and persian types (or options of them) are found by their type name, other
+//! fields can be marked with `#[persian]`, and `#[persian(skip)]` hides a field.
+//! fields take the same `format` and `digits` options as [`persian_serde`](crate::persian_serde),
+//! and `#[persian(layout = "..")]` on the struct replaces the whole output, naming fields in braces.
+//! a `format` with an unsupported specifier, or a time on a date-only field, doesn't compile
+//! ```rust
+//!use chrono::{DateTime, NaiveDate, Utc};
+//!use chrono_persian::{Digits, PersianDisplay};
+//!
+//!#[derive(PersianDisplay)]
+//!struct Invoice {
+//!    id: u32,
+//!    #[persian(format = "%Y/%m/%d", digits = "fa")]
+//!    issued: NaiveDate,
+//!    paid_at: Option<DateTime<Utc>>,
+//!    #[persian(skip)]
+//!    secret: &'static str,
+//!}
+//!
+//!let invoice = Invoice {
+//!    id: 7,
+//!    issued: NaiveDate::from_ymd_opt(2024, 11, 10).unwrap(),
+//!    paid_at: None,
+//!    secret: "",
+//!};
+//!assert_eq!(invoice.to_string(), "Invoice { id: 7, issued: ۱۴۰۳/۰۸/۲۰, paid_at: - }");
+//!
+//!#[derive(PersianDisplay)]
+//!#[persian(layout = "#{id} issued {issued}")]
+//!struct Short {
+//!    id: u32,
+//!    #[persian(format = "%Y/%m/%d")]
+//!    issued: NaiveDate,
+//!}
+//!
+//!let short = Short { id: 7, issued: invoice.issued };
+//!assert_eq!(short.to_string(), "#7 issued 1403/08/20");
+//!
+//! // a pattern the derive can't check is shown in place of the value
+//!let bad = chrono_persian::display::field(&short.issued, Some("%H:%M"), Digits::Latin);
+//!assert_eq!(bad.to_string(), "<invalid or unsupported format pattern>");
+//! ```
+//! ```rust,compile_fail
+//!use chrono::NaiveDate;
+//!use chrono_persian::PersianDisplay;
+//!
+//!#[derive(PersianDisplay)]
+//!struct Meeting {
+//!    #[persian(format = "%Y/%m/%d %H:%M")]
+//!    day: NaiveDate,
+//!}
+//! ```
+
+use std::fmt;
+
+use crate::format::{compile, write_items};
+use crate::{Digits, PersianError, PersianField};
+
+/// Shows a value in jalali, built by [`field`]
+#[derive(Debug, Clone, Copy)]
+pub struct FieldDisplay<'a, T> {
+    value: &'a T,
+    format: Option<&'static str>,
+    digits: Digits,
+}
+
+/// Shows an optional value in jalali, `None` is shown as `-`, built by [`option_field`]
+#[derive(Debug, Clone, Copy)]
+pub struct OptionFieldDisplay<'a, T> {
+    value: &'a Option<T>,
+    format: Option<&'static str>,
+    digits: Digits,
+}
+
+/// Show a value with `format` (or its default one) and `digits`
+pub fn field<'a, T: PersianField>(
+    value: &'a T,
+    format: Option<&'static str>,
+    digits: Digits,
+) -> FieldDisplay<'a, T> {
+    FieldDisplay {
+        value,
+        format,
+        digits,
+    }
+}
+
+/// Like [`field`] for an optional value
+pub fn option_field<'a, T: PersianField>(
+    value: &'a Option<T>,
+    format: Option<&'static str>,
+    digits: Digits,
+) -> OptionFieldDisplay<'a, T> {
+    OptionFieldDisplay {
+        value,
+        format,
+        digits,
+    }
+}
+
+/// a bad pattern is written in the output, `Display` must only fail when the writer does
+impl<T: PersianField> fmt::Display for FieldDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (date, time) = self.value.to_persian_fields();
+        let written = compile(self.format.unwrap_or(T::DEFAULT_FORMAT))
+            .and_then(|items| write_items(f, &items, date, time, self.digits));
+        match written {
+            Ok(()) => Ok(()),
+            Err(PersianError::WriteFailed) => Err(fmt::Error),
+            Err(e) => write!(f, "<{e}>"),
+        }
+    }
+}
+
+impl<T: PersianField> fmt::Display for OptionFieldDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Some(value) => field(value, self.format, self.digits).fmt(f),
+            None => f.write_str("-"),
+        }
+    }
+}
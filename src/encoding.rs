@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+use chrono::{DateTime, Days, NaiveDate};
+
+use crate::{PersianDate, PersianDateTime, PersianError};
+
+/// The version of the canonical encodings of [`PersianDate`]
+///
+/// the canonical string (its `Display`, `1403-08-20`), the packed integer
+/// ([`PersianDate::to_packed`]) and the day number ([`PersianDate::to_day_number`]) only change with this number, which is only bumped in a
+/// major release, so they're safe to hash or use as keys in content-addressed storage.
+/// the `Hash` impl is not covered, it depends on the hasher
+pub const ENCODING_VERSION: u32 = 1;
+
+/// The date of day number 0, 1300-01-01, the gregorian 1921-03-21
+pub const DAY_NUMBER_EPOCH: PersianDate = PersianDate::from_ymd_unchecked(1300, 1, 1);
+
+/// The day number of the unix epoch, 1348-10-11 (the gregorian 1970-01-01)
+///
+/// subtract it from a day number to get the `date32` of arrow and parquet and the days of most
+/// columnar databases, and add it to go back
+pub const UNIX_EPOCH_DAY_NUMBER: i32 = 17818;
+
+const EPOCH: NaiveDate = DateTime::UNIX_EPOCH
+    .naive_utc()
+    .date()
+    .checked_sub_days(Days::new(UNIX_EPOCH_DAY_NUMBER as u64))
+    .expect("1921-03-21 is in range");
+
+/// added to the year so the packed integers of negative years sort before positive ones
+const YEAR_BIAS: i64 = 1 << 31;
+
+impl PersianDate {
+    /// Pack the date into an integer, `(year + 2^31) << 9 | month << 5 | day`
+    ///
+    /// packed dates (and their big endian bytes) sort the same as the dates,
+    /// see [`ENCODING_VERSION`] for the stability guarantee
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///assert_eq!(date.to_packed(), 1099512346388);
+    ///assert_eq!(date.to_string(), "1403-08-20");
+    ///assert_eq!(PersianDate::from_packed(1099512346388), Some(date));
+    ///
+    ///let next = PersianDate::from_ymd_opt(1403, 8, 21).unwrap();
+    ///assert!(date.to_packed().to_be_bytes() < next.to_packed().to_be_bytes());
+    /// ```
+    pub fn to_packed(&self) -> u64 {
+        let year = (self.year() as i64 + YEAR_BIAS) as u64;
+        year << 9 | (self.month() as u64) << 5 | self.day() as u64
+    }
+
+    /// Unpack a date made by [`PersianDate::to_packed`], `None` if it's not a valid date
+    pub fn from_packed(packed: u64) -> Option<Self> {
+        let year = i32::try_from((packed >> 9) as i64 - YEAR_BIAS).ok()?;
+        let month = (packed >> 5 & 0b1111) as u32;
+        let day = (packed & 0b11111) as u32;
+        Self::from_ymd_opt(year, month, day)
+    }
+
+    /// the number of days since [`DAY_NUMBER_EPOCH`], negative before it
+    ///
+    /// the dates of the last and the coming century have small numbers, see
+    /// [`UNIX_EPOCH_DAY_NUMBER`] for the `date32` of columnar formats and
+    /// [`ENCODING_VERSION`] for the stability guarantee
+    /// ```rust
+    ///use chrono_persian::{PersianDate, DAY_NUMBER_EPOCH, UNIX_EPOCH_DAY_NUMBER};
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///assert_eq!(date.to_day_number(), 37855);
+    ///assert_eq!(PersianDate::from_day_number(37855), Some(date));
+    ///assert_eq!(DAY_NUMBER_EPOCH.to_day_number(), 0);
+    ///assert_eq!(PersianDate::from_day_number(-1).unwrap().to_string(), "1299-12-29");
+    ///
+    /// // the date32 of 2024-11-10
+    ///assert_eq!(date.to_day_number() - UNIX_EPOCH_DAY_NUMBER, 20037);
+    ///let unix_epoch = PersianDate::from_day_number(UNIX_EPOCH_DAY_NUMBER).unwrap();
+    ///assert_eq!(unix_epoch.to_string(), "1348-10-11");
+    /// ```
+    pub fn to_day_number(&self) -> i32 {
+        let date = self
+            .to_gregorian()
+            .expect("persian dates are in chrono's range");
+        let days = date.signed_duration_since(EPOCH).num_days();
+        days as i32
+    }
+
+    /// the date a number of days after [`DAY_NUMBER_EPOCH`], `None` if out of range
+    pub fn from_day_number(days: i32) -> Option<Self> {
+        let date = match days {
+            0.. => EPOCH.checked_add_days(Days::new(days as u64))?,
+            _ => EPOCH.checked_sub_days(Days::new(days.unsigned_abs() as u64))?,
+        };
+        Some(Self::from(date))
+    }
+
+    /// the days from `other` to the date, negative when `other` is after it
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1404, 1, 1).unwrap();
+    ///let other = PersianDate::from_ymd_opt(1403, 12, 15).unwrap();
+    ///assert_eq!(date.days_since(other), 16);
+    ///assert_eq!(other.days_since(date), -16);
+    ///assert_eq!(date.weeks_since(other), 2);
+    ///assert_eq!(other.weeks_since(date), -2);
+    /// ```
+    pub fn days_since(&self, other: PersianDate) -> i64 {
+        self.to_day_number() as i64 - other.to_day_number() as i64
+    }
+
+    /// the whole weeks from `other` to the date, rounded towards zero
+    pub fn weeks_since(&self, other: PersianDate) -> i64 {
+        self.days_since(other) / 7
+    }
+}
+
+impl PersianDateTime {
+    /// the calendar days from `other`'s date to this one, each in its own offset, not the
+    /// elapsed time
+    /// ```rust
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let evening = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 23, 0, 0).unwrap();
+    ///let after_midnight = PersianDateTime::from_ymd_hms_opt(1403, 8, 21, 1, 0, 0).unwrap();
+    ///assert_eq!(after_midnight.days_since(&evening), 1);
+    ///assert_eq!(after_midnight.weeks_since(&evening), 0);
+    /// ```
+    pub fn days_since(&self, other: &PersianDateTime) -> i64 {
+        self.date().days_since(other.date())
+    }
+
+    /// the whole weeks of [`PersianDateTime::days_since`], rounded towards zero
+    pub fn weeks_since(&self, other: &PersianDateTime) -> i64 {
+        self.date().weeks_since(other.date())
+    }
+}
+
+/// Encode a column of dates as [`PersianDate::to_day_number`]s
+/// ```rust
+///use chrono_persian::{decode_day_numbers, encode_day_numbers, PersianDate, PersianError};
+///
+///let column = ["1403/08/20", "1403/12/30", "1404/01/01"].map(|d| d.parse::<PersianDate>().unwrap());
+///let encoded = encode_day_numbers(&column);
+///assert_eq!(encoded, [37855, 37985, 37986]);
+///assert_eq!(decode_day_numbers(&encoded).unwrap(), column);
+///assert_eq!(decode_day_numbers(&[0, i32::MAX]), Err(PersianError::OutOfRange));
+/// ```
+pub fn encode_day_numbers(dates: &[PersianDate]) -> Vec<i32> {
+    dates.iter().map(PersianDate::to_day_number).collect()
+}
+
+/// Decode a column of [`PersianDate::to_day_number`]s, [`PersianError::OutOfRange`] if one
+/// of them is out of range
+///
+/// the days are walked in order, so sorted columns only do the calendar math at month
+/// boundaries
+pub fn decode_day_numbers(days: &[i32]) -> Result<Vec<PersianDate>, PersianError> {
+    let mut out = Vec::with_capacity(days.len());
+    let mut previous: Option<(i32, PersianDate)> = None;
+    for &day in days {
+        let date = match previous {
+            Some((p, date)) if day == p => Some(date),
+            Some((p, date)) if day.checked_sub(p) == Some(1) => date.succ_opt(),
+            _ => PersianDate::from_day_number(day),
+        };
+        let date = date.ok_or(PersianError::OutOfRange)?;
+        previous = Some((day, date));
+        out.push(date);
+    }
+    Ok(out)
+}
@@ -0,0 +1,122 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{calendar, PersianDate};
+
+/// Reasons a persian date can't be parsed or constructed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PersianError {
+    /// the input doesn't look like a date in the expected format
+    InvalidFormat,
+    /// the components don't form a date in the persian calendar, e.g. `1403-07-31`
+    InvalidJalaliDate { year: i32, month: u32, day: u32 },
+    /// the format pattern has an unknown specifier, or one the value can't fill
+    InvalidPattern,
+    /// the value is valid but outside the range chrono can represent
+    OutOfRange,
+    /// the hour, minute or second doesn't form a time of day
+    InvalidTime { hour: u32, minute: u32, second: u32 },
+    /// the wall clock time happened twice, when the clocks were turned back
+    AmbiguousLocalTime,
+    /// the wall clock time never happened, when the clocks were turned forward
+    NonexistentLocalTime,
+    /// no year has the month and day, such as a yearly date of 31 mehr
+    InvalidMonthDay { month: u32, day: u32 },
+    /// the writer the value was formatted into failed
+    WriteFailed,
+    /// the formatted value is longer than the `capacity` bytes of its buffer
+    OutputTooLong { capacity: usize },
+}
+
+impl PersianError {
+    /// Get the valid dates closest to an invalid jalali date, for a "did you mean" prompt
+    ///
+    /// empty for the other errors
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianError};
+    ///
+    ///let err = "1403/12/31".parse::<PersianDate>().unwrap_err();
+    ///let suggestions: Vec<_> = err.suggestions().iter().map(|d| d.to_string()).collect();
+    ///assert_eq!(suggestions, ["1403-12-30", "1404-01-01"]);
+    ///
+    ///let err = "1403/13/05".parse::<PersianDate>().unwrap_err();
+    ///let suggestions: Vec<_> = err.suggestions().iter().map(|d| d.to_string()).collect();
+    ///assert_eq!(suggestions, ["1403-12-05", "1404-01-05"]);
+    /// ```
+    pub fn suggestions(&self) -> Vec<PersianDate> {
+        let PersianError::InvalidJalaliDate { year, month, day } = *self else {
+            return Vec::new();
+        };
+        let clamped = |y: i32, m: u32, d: u32| {
+            PersianDate::from_ymd_opt(y, m, d.clamp(1, calendar::days_in_month(y, m)))
+        };
+        let candidates = match month {
+            0 => [
+                clamped(year.saturating_sub(1), 12, day),
+                clamped(year, 1, day),
+            ],
+            13.. => [
+                clamped(year, 12, day),
+                clamped(year.saturating_add(1), 1, day),
+            ],
+            _ if day == 0 => {
+                let (y, m) = if month == 1 {
+                    (year.saturating_sub(1), 12)
+                } else {
+                    (year, month - 1)
+                };
+                [clamped(y, m, 31), clamped(year, month, 1)]
+            }
+            _ => {
+                let (y, m) = if month == 12 {
+                    (year.saturating_add(1), 1)
+                } else {
+                    (year, month + 1)
+                };
+                [clamped(year, month, day), clamped(y, m, 1)]
+            }
+        };
+        let mut dates: Vec<PersianDate> = candidates.into_iter().flatten().collect();
+        dates.dedup();
+        dates
+    }
+}
+
+impl fmt::Display for PersianError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersianError::InvalidFormat => {
+                f.write_str("invalid date format, expected year/month/day")
+            }
+            PersianError::InvalidJalaliDate { year, month, day } => {
+                write!(
+                    f,
+                    "{year:04}/{month:02}/{day:02} is not a valid jalali date"
+                )
+            }
+            PersianError::InvalidPattern => f.write_str("invalid or unsupported format pattern"),
+            PersianError::OutOfRange => f.write_str("date out of the supported range"),
+            PersianError::InvalidTime {
+                hour,
+                minute,
+                second,
+            } => write!(f, "{hour:02}:{minute:02}:{second:02} is not a valid time"),
+            PersianError::AmbiguousLocalTime => {
+                f.write_str("local time is ambiguous, it happened twice")
+            }
+            PersianError::NonexistentLocalTime => {
+                f.write_str("local time doesn't exist, the clocks skipped it")
+            }
+            PersianError::InvalidMonthDay { month, day } => {
+                write!(f, "{month:02}/{day:02} is not a day of any jalali year")
+            }
+            PersianError::WriteFailed => f.write_str("the formatted output couldn't be written"),
+            PersianError::OutputTooLong { capacity } => {
+                write!(f, "the formatted output is longer than {capacity} bytes")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PersianError {}
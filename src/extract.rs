@@ -0,0 +1,87 @@
+//! Web framework extractors (requires the `axum` or `actix-web` feature)
+//!
+//! with either feature [`PersianDate`](crate::PersianDate) is deserializable, so it works with
+//! the framework's own `Path`/`Query` extractors and invalid dates are rejected with `400`.
+//! [`PersianDateRange`] can also be extracted directly from the `from` and `to` query parameters
+
+use crate::PersianDateRange;
+
+#[cfg(feature = "axum")]
+impl<S: Send + Sync> axum::extract::FromRequestParts<S> for PersianDateRange {
+    type Rejection = (axum::http::StatusCode, String);
+
+    /// Extract the range from `?from=..&to=..`, rejecting invalid ranges with `400`
+    /// ```rust
+    ///use axum::extract::{FromRequestParts, Path};
+    ///use axum::http::{Request, StatusCode};
+    ///use chrono_persian::{PersianDate, PersianDateRange};
+    ///use std::future::Future;
+    ///use std::task::{Context, Poll, Waker};
+    ///
+    ///async fn day_report(Path(day): Path<PersianDate>) -> String {
+    ///    format!("report of {day}")
+    ///}
+    ///
+    ///async fn range_report(range: PersianDateRange) -> String {
+    ///    format!("report from {} to {}", range.start(), range.end())
+    ///}
+    ///
+    ///let _: axum::Router = axum::Router::new()
+    ///    .route("/reports/{day}", axum::routing::get(day_report))
+    ///    .route("/reports", axum::routing::get(range_report));
+    ///
+    ///let extract = |uri: &str| {
+    ///    let (mut parts, _) = Request::get(uri).body(()).unwrap().into_parts();
+    ///    let fut = std::pin::pin!(PersianDateRange::from_request_parts(&mut parts, &()));
+    ///    match fut.poll(&mut Context::from_waker(Waker::noop())) {
+    ///        Poll::Ready(r) => r,
+    ///        Poll::Pending => unreachable!(),
+    ///    }
+    ///};
+    ///
+    ///let range = extract("/reports?from=1403/01/01&to=1403/06/31").unwrap();
+    ///assert_eq!(range.end().to_string(), "1403-06-31");
+    ///
+    ///let (status, _) = extract("/reports?from=1403/01/01&to=1403/06/32").unwrap_err();
+    ///assert_eq!(status, StatusCode::BAD_REQUEST);
+    /// ```
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        axum::extract::Query::<PersianDateRange>::from_request_parts(parts, state)
+            .await
+            .map(|q| q.0)
+            .map_err(|e| (axum::http::StatusCode::BAD_REQUEST, e.body_text()))
+    }
+}
+
+#[cfg(feature = "actix-web")]
+impl actix_web::FromRequest for PersianDateRange {
+    type Error = actix_web::Error;
+    type Future = std::future::Ready<Result<Self, Self::Error>>;
+
+    /// Extract the range from `?from=..&to=..`, rejecting invalid ranges with `400`
+    /// ```rust
+    ///use actix_web::{dev::Payload, http::StatusCode, test::TestRequest, FromRequest};
+    ///use chrono_persian::PersianDateRange;
+    ///
+    ///let extract = |uri: &str| {
+    ///    let req = TestRequest::with_uri(uri).to_http_request();
+    ///    PersianDateRange::from_request(&req, &mut Payload::None).into_inner()
+    ///};
+    ///
+    ///let range = extract("/reports?from=1403/01/01&to=1403/06/31").unwrap();
+    ///assert_eq!(range.start().to_string(), "1403-01-01");
+    ///
+    ///let err = extract("/reports?from=1403/06/31&to=1403/01/01").unwrap_err();
+    ///assert_eq!(err.as_response_error().status_code(), StatusCode::BAD_REQUEST);
+    /// ```
+    fn from_request(req: &actix_web::HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
+        std::future::ready(
+            actix_web::web::Query::<PersianDateRange>::from_query(req.query_string())
+                .map(|q| q.into_inner())
+                .map_err(|e| actix_web::error::ErrorBadRequest(e.to_string())),
+        )
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::{NaiveDate, Weekday};
+
+use crate::{animal_of_year, calendar, is_valid_jalali, Animal, PersianDate, Weekend};
+
+/// Quick facts about a persian year, the ones event planners ask for every year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearFacts {
+    pub year: i32,
+    /// whether esfand has 30 days
+    pub is_leap: bool,
+    /// number of days, 365 or 366
+    pub days: u32,
+    /// the gregorian date of 1 farvardin
+    pub nowruz: NaiveDate,
+    /// the day of the week of 1 farvardin
+    pub nowruz_weekday: Weekday,
+    /// the day of the week of 13 farvardin (sizdah bedar)
+    pub sizdah_bedar_weekday: Weekday,
+    pub animal: Animal,
+}
+
+impl YearFacts {
+    /// whether sizdah bedar falls on the thursday/friday weekend
+    pub fn sizdah_bedar_on_weekend(&self) -> bool {
+        self.sizdah_bedar_on(Weekend::default())
+    }
+
+    /// whether sizdah bedar falls on the `weekend`
+    pub fn sizdah_bedar_on(&self, weekend: Weekend) -> bool {
+        weekend.contains(self.sizdah_bedar_weekday)
+    }
+}
+
+/// Get the quick facts of a persian year, `None` when out of range
+/// ```rust
+///use chrono::Weekday;
+///use chrono_persian::{year_facts, Animal};
+///
+///let facts = year_facts(1404).unwrap();
+///assert_eq!(facts.nowruz.to_string(), "2025-03-21");
+///assert_eq!(facts.nowruz_weekday, Weekday::Fri);
+///assert_eq!(facts.sizdah_bedar_weekday, Weekday::Wed);
+///assert!(!facts.sizdah_bedar_on_weekend());
+///assert!(!facts.is_leap);
+///assert_eq!(facts.animal, Animal::Snake);
+/// ```
+pub fn year_facts(year: i32) -> Option<YearFacts> {
+    let nowruz = PersianDate::from_ymd_opt(year, 1, 1)?;
+    let sizdah_bedar = PersianDate::from_ymd_opt(year, 1, 13)?;
+    let is_leap = calendar::is_leap_year(year);
+    Some(YearFacts {
+        year,
+        is_leap,
+        days: days_in_jalali_year(year),
+        nowruz: nowruz.to_gregorian()?,
+        nowruz_weekday: nowruz.weekday(),
+        sizdah_bedar_weekday: sizdah_bedar.weekday(),
+        animal: animal_of_year(year),
+    })
+}
+
+/// Get the day of the week of 1 farvardin of a persian year, `None` when out of range
+/// ```rust
+///use chrono::Weekday;
+///use chrono_persian::nowruz_weekday;
+///
+///assert_eq!(nowruz_weekday(1403), Some(Weekday::Wed));
+/// ```
+pub fn nowruz_weekday(year: i32) -> Option<Weekday> {
+    Some(PersianDate::from_ymd_opt(year, 1, 1)?.weekday())
+}
+
+/// whether esfand has 30 days in a persian year
+/// ```rust
+///use chrono_persian::is_jalali_leap_year;
+///
+///assert!(is_jalali_leap_year(1403));
+///assert!(!is_jalali_leap_year(1404));
+/// ```
+pub fn is_jalali_leap_year(year: i32) -> bool {
+    calendar::is_leap_year(year)
+}
+
+/// Get the number of days in a persian year, 365 or 366
+pub fn days_in_jalali_year(year: i32) -> u32 {
+    if calendar::is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Get the number of days in a persian month, `None` if the month isn't from 1 to 12
+/// ```rust
+///use chrono_persian::days_in_jalali_month;
+///
+///assert_eq!(days_in_jalali_month(1403, 1), Some(31));
+///assert_eq!(days_in_jalali_month(1403, 7), Some(30));
+///assert_eq!(days_in_jalali_month(1404, 12), Some(29));
+///assert_eq!(days_in_jalali_month(1404, 13), None);
+/// ```
+pub fn days_in_jalali_month(year: i32, month: u32) -> Option<u32> {
+    (1..=12)
+        .contains(&month)
+        .then(|| calendar::days_in_month(year, month))
+}
+
+/// Get the day of the year of a persian date, from 1 (farvardin 1st) to 366, `None` if it's
+/// not a valid date
+/// ```rust
+///use chrono_persian::jalali_ordinal;
+///
+///assert_eq!(jalali_ordinal(1403, 1, 1), Some(1));
+///assert_eq!(jalali_ordinal(1403, 7, 1), Some(187));
+///assert_eq!(jalali_ordinal(1403, 12, 30), Some(366));
+///assert_eq!(jalali_ordinal(1404, 12, 30), None);
+/// ```
+pub fn jalali_ordinal(year: i32, month: u32, day: u32) -> Option<u32> {
+    if !is_valid_jalali(year, month, day) {
+        return None;
+    }
+    // the first six months have 31 days and the next five 30
+    let before = match month {
+        1..=7 => (month - 1) * 31,
+        _ => 186 + (month - 7) * 30,
+    };
+    Some(before + day)
+}
+
+/// Get the week of the year of a persian date, weeks start on saturday and week 1 is the one
+/// with farvardin 1st, like a wall calendar, `None` if it's not a valid date
+///
+/// see [`PersianDate::iso_week_persian`] for weeks that don't split across years
+/// ```rust
+///use chrono_persian::jalali_week_of_year;
+///
+/// // 1404 starts on a friday, so saturday the 2nd starts week 2
+///assert_eq!(jalali_week_of_year(1404, 1, 1), Some(1));
+///assert_eq!(jalali_week_of_year(1404, 1, 2), Some(2));
+///assert_eq!(jalali_week_of_year(1404, 12, 29), Some(53));
+/// ```
+pub fn jalali_week_of_year(year: i32, month: u32, day: u32) -> Option<u32> {
+    let ordinal = jalali_ordinal(year, month, day)?;
+    let nowruz = PersianDate::from_ymd_opt(year, 1, 1)?.weekday();
+    let offset = (nowruz.num_days_from_monday() + 2) % 7;
+    Some((ordinal - 1 + offset) / 7 + 1)
+}
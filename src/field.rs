@@ -0,0 +1,96 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use crate::resolve::tehran_local;
+use crate::{PersianDate, PersianDateTime, Resolve};
+
+/// A type that can be written as a jalali string
+///
+/// chrono types are shown in iran's time, `NaiveDateTime` is taken as utc
+pub trait PersianField: Sized {
+    /// the pattern used when the field doesn't have one
+    const DEFAULT_FORMAT: &'static str;
+
+    /// the jalali date and, if the type has one, time of the value
+    fn to_persian_fields(&self) -> (PersianDate, Option<NaiveTime>);
+
+    /// build the value back, a missing time is midnight
+    fn from_persian_fields(date: PersianDate, time: Option<NaiveTime>) -> Option<Self>;
+}
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+impl PersianField for PersianDate {
+    const DEFAULT_FORMAT: &'static str = DATE_FORMAT;
+
+    fn to_persian_fields(&self) -> (PersianDate, Option<NaiveTime>) {
+        (*self, None)
+    }
+
+    fn from_persian_fields(date: PersianDate, _: Option<NaiveTime>) -> Option<Self> {
+        Some(date)
+    }
+}
+
+impl PersianField for NaiveDate {
+    const DEFAULT_FORMAT: &'static str = DATE_FORMAT;
+
+    fn to_persian_fields(&self) -> (PersianDate, Option<NaiveTime>) {
+        (PersianDate::from(*self), None)
+    }
+
+    fn from_persian_fields(date: PersianDate, _: Option<NaiveTime>) -> Option<Self> {
+        date.to_gregorian()
+    }
+}
+
+impl PersianField for PersianDateTime {
+    const DEFAULT_FORMAT: &'static str = DATETIME_FORMAT;
+
+    fn to_persian_fields(&self) -> (PersianDate, Option<NaiveTime>) {
+        (self.date(), Some(self.time()))
+    }
+
+    fn from_persian_fields(date: PersianDate, time: Option<NaiveTime>) -> Option<Self> {
+        DateTime::<FixedOffset>::from_persian_fields(date, time).map(PersianDateTime::from)
+    }
+}
+
+impl PersianField for DateTime<FixedOffset> {
+    const DEFAULT_FORMAT: &'static str = DATETIME_FORMAT;
+
+    fn to_persian_fields(&self) -> (PersianDate, Option<NaiveTime>) {
+        PersianDateTime::from(*self).to_persian_fields()
+    }
+
+    fn from_persian_fields(date: PersianDate, time: Option<NaiveTime>) -> Option<Self> {
+        let local = date
+            .to_gregorian()?
+            .and_time(time.unwrap_or(NaiveTime::MIN));
+        tehran_local(local, Resolve::Earliest)
+    }
+}
+
+impl PersianField for DateTime<Utc> {
+    const DEFAULT_FORMAT: &'static str = DATETIME_FORMAT;
+
+    fn to_persian_fields(&self) -> (PersianDate, Option<NaiveTime>) {
+        PersianDateTime::from(*self).to_persian_fields()
+    }
+
+    fn from_persian_fields(date: PersianDate, time: Option<NaiveTime>) -> Option<Self> {
+        DateTime::<FixedOffset>::from_persian_fields(date, time).map(|d| d.to_utc())
+    }
+}
+
+impl PersianField for NaiveDateTime {
+    const DEFAULT_FORMAT: &'static str = DATETIME_FORMAT;
+
+    fn to_persian_fields(&self) -> (PersianDate, Option<NaiveTime>) {
+        self.and_utc().to_persian_fields()
+    }
+
+    fn from_persian_fields(date: PersianDate, time: Option<NaiveTime>) -> Option<Self> {
+        DateTime::<Utc>::from_persian_fields(date, time).map(|d| d.naive_utc())
+    }
+}
@@ -0,0 +1,72 @@
+use alloc::{format, string::String};
+use chrono::Timelike;
+
+use crate::{PersianDate, PersianDateTime};
+
+/// How much of the time [`PersianDateTime::to_filename_with`] includes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FilenamePrecision {
+    /// `1403-08-20`
+    Day,
+    /// `1403-08-20_2307`
+    #[default]
+    Minute,
+    /// `1403-08-20_230705`
+    Second,
+    /// `1403-08-20_230705_123`
+    Millisecond,
+}
+
+impl PersianDate {
+    /// Format the date for use in file names, such as `1403-08-20`
+    pub fn to_filename(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year(), self.month(), self.day())
+    }
+}
+
+impl PersianDateTime {
+    /// Format the date and time (to the minute) for use in file names, such as `1403-08-20_2307`
+    ///
+    /// the result only ever contains ascii digits, `-` and `_`,
+    /// and sorts the same as the instants for years 0 to 9999
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let utc = "2024-11-09 19:37:05.123 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///assert_eq!(PersianDateTime::from(utc).to_filename(), "1403-08-19_2307");
+    /// ```
+    pub fn to_filename(&self) -> String {
+        self.to_filename_with(FilenamePrecision::Minute)
+    }
+
+    /// Format the date and time with the given precision, see [`PersianDateTime::to_filename`]
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::{FilenamePrecision, PersianDateTime};
+    ///
+    ///let p = PersianDateTime::from("2024-11-09 19:37:05.123 UTC".parse::<DateTime<Utc>>().unwrap());
+    ///assert_eq!(p.to_filename_with(FilenamePrecision::Day), "1403-08-19");
+    ///assert_eq!(p.to_filename_with(FilenamePrecision::Second), "1403-08-19_230705");
+    ///assert_eq!(p.to_filename_with(FilenamePrecision::Millisecond), "1403-08-19_230705_123");
+    /// ```
+    pub fn to_filename_with(&self, precision: FilenamePrecision) -> String {
+        let date = self.date().to_filename();
+        let t = self.time();
+        match precision {
+            FilenamePrecision::Day => date,
+            FilenamePrecision::Minute => format!("{date}_{:02}{:02}", t.hour(), t.minute()),
+            FilenamePrecision::Second => {
+                format!("{date}_{:02}{:02}{:02}", t.hour(), t.minute(), t.second())
+            }
+            FilenamePrecision::Millisecond => format!(
+                "{date}_{:02}{:02}{:02}_{:03}",
+                t.hour(),
+                t.minute(),
+                t.second(),
+                // a leap second stays within the last millisecond
+                (t.nanosecond() / 1_000_000).min(999)
+            ),
+        }
+    }
+}
@@ -0,0 +1,611 @@
+use alloc::{string::String, vec::Vec};
+use chrono::{Duration, NaiveTime, Timelike};
+use core::fmt::{self, Write};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::date::split_ymd;
+use crate::names::{MONTH_NAMES_FA, WEEKDAY_NAMES_FA};
+use crate::{PersianDate, PersianDateTime, PersianError, Resolve};
+
+/// The digits numbers are written with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Digits {
+    /// `0123456789`
+    #[default]
+    Latin,
+    /// `۰۱۲۳۴۵۶۷۸۹`
+    Persian,
+}
+
+/// How the time is rounded before it's written, so a pattern without `%S` doesn't have to show
+/// a truncated or rounded time by hand
+///
+/// rounding can move the date, `23:59:45` rounded to the minute is midnight of the next day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rounding {
+    /// the time as it is
+    #[default]
+    Exact,
+    /// drop the fraction of the second
+    TruncateToSecond,
+    /// to the nearest second, half a second rounds up
+    RoundToSecond,
+    /// drop the seconds
+    TruncateToMinute,
+    /// to the nearest minute, 30 seconds round up
+    RoundToMinute,
+}
+
+impl Rounding {
+    /// the rounded instant, `None` when it's out of range
+    fn apply(self, value: &PersianDateTime) -> Option<PersianDateTime> {
+        // a leap second counts as a second past 59
+        let nanos = value.second() as i64 * 1_000_000_000 + value.nanosecond() as i64;
+        let (unit, round) = match self {
+            Rounding::Exact => return Some(*value),
+            Rounding::TruncateToSecond => (1_000_000_000, false),
+            Rounding::RoundToSecond => (1_000_000_000, true),
+            Rounding::TruncateToMinute => (60_000_000_000, false),
+            Rounding::RoundToMinute => (60_000_000_000, true),
+        };
+        let below = nanos % unit;
+        let step = match round && below * 2 >= unit {
+            true => unit - below,
+            false => -below,
+        };
+        value.checked_add_signed(Duration::nanoseconds(step))
+    }
+}
+
+/// a piece of a compiled pattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Item {
+    Literal(String),
+    /// `%Y`, at least 4 digits
+    Year,
+    /// `%m`, 2 digits
+    Month,
+    /// `%d`, 2 digits
+    Day,
+    /// `%H`, 2 digits
+    Hour,
+    /// `%M`, 2 digits
+    Minute,
+    /// `%S`, 2 digits, `60` is a leap second
+    Second,
+    /// `%B`, the persian name of the month
+    MonthName,
+    /// `%A`, the persian name of the day of the week
+    WeekdayName,
+}
+
+impl Item {
+    fn is_numeric(&self) -> bool {
+        !matches!(self, Item::Literal(_) | Item::MonthName | Item::WeekdayName)
+    }
+
+    fn is_time(&self) -> bool {
+        matches!(self, Item::Hour | Item::Minute | Item::Second)
+    }
+}
+
+/// split a strftime-like pattern into its items
+pub(crate) fn compile(pattern: &str) -> Result<Vec<Item>, PersianError> {
+    let mut items = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+        let item = match chars.next().ok_or(PersianError::InvalidPattern)? {
+            '%' => {
+                literal.push('%');
+                continue;
+            }
+            'Y' => Item::Year,
+            'm' => Item::Month,
+            'd' => Item::Day,
+            'H' => Item::Hour,
+            'M' => Item::Minute,
+            'S' => Item::Second,
+            'B' => Item::MonthName,
+            'A' => Item::WeekdayName,
+            _ => return Err(PersianError::InvalidPattern),
+        };
+        if !literal.is_empty() {
+            items.push(Item::Literal(core::mem::take(&mut literal)));
+        }
+        items.push(item);
+    }
+    if !literal.is_empty() {
+        items.push(Item::Literal(literal));
+    }
+    Ok(items)
+}
+
+/// A strftime-like pattern parsed once, to format and parse many values with
+///
+/// the pattern supports `%Y %m %d %H %M %S`, `%B` (the month's name), `%A` (the weekday's
+/// name) and `%%`, names are in persian and parsing accepts latin, persian and arabic digits
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::{CompiledFormat, Digits, PersianDateTime};
+///
+///let format = CompiledFormat::new("%Y/%m/%d %H:%M").unwrap();
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///assert_eq!(format.format(&PersianDateTime::from(utc)), "1403/08/20 02:08");
+///
+///let a = format.parse("۱۴۰۳/۰۸/۲۰ ۰۲:۰۸").unwrap();
+///assert_eq!(a.to_utc().to_string(), "2024-11-09 22:38:00 UTC");
+///
+///let fa = format.with_digits(Digits::Persian);
+///assert_eq!(fa.format(&a), "۱۴۰۳/۰۸/۲۰ ۰۲:۰۸");
+///
+///let names = CompiledFormat::new("%A %d %B %Y").unwrap();
+///let date = names.parse_date("یکشنبه ۲۰ آبان ۱۴۰۳").unwrap();
+///assert_eq!(date.to_string(), "1403-08-20");
+///assert!(names.parse_date("دوشنبه ۲۰ آبان ۱۴۰۳").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompiledFormat {
+    items: Vec<Item>,
+    digits: Digits,
+    rounding: Rounding,
+}
+
+impl CompiledFormat {
+    /// Parse `pattern`, fails on unknown specifiers
+    pub fn new(pattern: &str) -> Result<Self, PersianError> {
+        Ok(Self {
+            items: compile(pattern)?,
+            digits: Digits::Latin,
+            rounding: Rounding::Exact,
+        })
+    }
+
+    /// the same format writing the numbers with `digits`
+    pub fn with_digits(mut self, digits: Digits) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// the same format rounding the time with `rounding`, dates are written as they are
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::{CompiledFormat, PersianDateTime, Rounding};
+    ///
+    ///let utc = "2024-11-09 22:38:45.600 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let a = PersianDateTime::from(utc);
+    ///let minutes = CompiledFormat::new("%Y/%m/%d %H:%M").unwrap();
+    ///assert_eq!(minutes.format(&a), "1403/08/20 02:08");
+    ///assert_eq!(minutes.clone().with_rounding(Rounding::RoundToMinute).format(&a), "1403/08/20 02:09");
+    ///
+    ///let seconds = CompiledFormat::new("%H:%M:%S").unwrap().with_rounding(Rounding::RoundToSecond);
+    ///assert_eq!(seconds.format(&a), "02:08:46");
+    ///
+    ///let late = PersianDateTime::from_ymd_hms_opt(1403, 12, 30, 23, 59, 45).unwrap();
+    ///assert_eq!(minutes.with_rounding(Rounding::RoundToMinute).format(&late), "1404/01/01 00:00");
+    /// ```
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Format a datetime
+    pub fn format(&self, value: &PersianDateTime) -> String {
+        let mut out = String::new();
+        self.write(&mut out, value)
+            .expect("writing a datetime to a string can't fail");
+        out
+    }
+
+    /// Format a date, fails if the pattern has time specifiers
+    /// ```rust
+    ///use chrono_persian::{CompiledFormat, PersianDate};
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///let a = CompiledFormat::new("%d-%m-%Y").unwrap();
+    ///assert_eq!(a.format_date(date).unwrap(), "20-08-1403");
+    ///assert!(CompiledFormat::new("%Y %H").unwrap().format_date(date).is_err());
+    /// ```
+    pub fn format_date(&self, date: PersianDate) -> Result<String, PersianError> {
+        let mut out = String::new();
+        write_items(&mut out, &self.items, date, None, self.digits)?;
+        Ok(out)
+    }
+
+    /// Write a datetime to `out`, fails with [`PersianError::WriteFailed`] when `out` does
+    pub fn write<W: Write>(
+        &self,
+        out: &mut W,
+        value: &PersianDateTime,
+    ) -> Result<(), PersianError> {
+        let value = self.rounding.apply(value).unwrap_or(*value);
+        write_items(
+            out,
+            &self.items,
+            value.date(),
+            Some(value.time()),
+            self.digits,
+        )
+    }
+
+    /// Parse a wall clock time in iran, missing time fields are zero
+    pub fn parse(&self, s: &str) -> Result<PersianDateTime, PersianError> {
+        let (date, time) = parse_items(&self.items, s)?;
+        PersianDateTime::from_local(date, time.unwrap_or(NaiveTime::MIN), Resolve::Earliest)
+            .ok_or(PersianError::OutOfRange)
+    }
+
+    /// Parse a date, time fields are read but ignored
+    pub fn parse_date(&self, s: &str) -> Result<PersianDate, PersianError> {
+        Ok(parse_items(&self.items, s)?.0)
+    }
+}
+
+impl PersianDate {
+    /// Format the date with a strftime-like pattern, see [`CompiledFormat`]
+    /// ```rust
+    ///use chrono_persian::{Digits, PersianDate};
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 24).unwrap();
+    ///assert_eq!(date.format("%A %d %B %Y", Digits::Persian).unwrap(), "پنج‌شنبه ۲۴ آبان ۱۴۰۳");
+    ///assert_eq!(date.format("%Y/%m/%d", Digits::Latin).unwrap(), "1403/08/24");
+    /// ```
+    pub fn format(&self, pattern: &str, digits: Digits) -> Result<String, PersianError> {
+        CompiledFormat::new(pattern)?
+            .with_digits(digits)
+            .format_date(*self)
+    }
+
+    /// Parse a date with a strftime-like pattern, see [`CompiledFormat`]
+    pub fn parse_from_str(s: &str, pattern: &str) -> Result<Self, PersianError> {
+        CompiledFormat::new(pattern)?.parse_date(s)
+    }
+}
+
+impl PersianDateTime {
+    /// Format the date and time with a strftime-like pattern, see [`CompiledFormat`]
+    /// ```rust
+    ///use chrono_persian::{Digits, PersianDateTime};
+    ///
+    ///let a = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 23, 7, 0).unwrap();
+    ///assert_eq!(a.format("%A %d %B، %H:%M", Digits::Persian).unwrap(), "یکشنبه ۲۰ آبان، ۲۳:۰۷");
+    /// ```
+    pub fn format(&self, pattern: &str, digits: Digits) -> Result<String, PersianError> {
+        Ok(CompiledFormat::new(pattern)?
+            .with_digits(digits)
+            .format(self))
+    }
+
+    /// Parse a wall clock time in iran with a strftime-like pattern, see [`CompiledFormat`]
+    /// ```rust
+    ///use chrono::NaiveDateTime;
+    ///use chrono_persian::{PersianDateTime, PersianError};
+    ///
+    ///let a = PersianDateTime::parse_from_str("1403/08/20 23:07", "%Y/%m/%d %H:%M").unwrap();
+    ///assert_eq!(a.to_string(), "1403-08-20 23:07:00 +03:30");
+    ///assert_eq!(a.to_gregorian::<NaiveDateTime>().unwrap().to_string(), "2024-11-10 19:37:00");
+    ///
+    ///let err = PersianDateTime::parse_from_str("1404/12/30 10:00", "%Y/%m/%d %H:%M");
+    ///assert_eq!(err, Err(PersianError::InvalidJalaliDate { year: 1404, month: 12, day: 30 }));
+    /// ```
+    pub fn parse_from_str(s: &str, pattern: &str) -> Result<Self, PersianError> {
+        CompiledFormat::new(pattern)?.parse(s)
+    }
+
+    /// Parse a wall clock time in iran written in a common way, `year/month/day` with `/` or
+    /// `-`, optionally followed by a space or `T` and `hh:mm` or `hh:mm:ss`, in any digits
+    /// ```rust
+    ///use chrono_persian::PersianDateTime;
+    ///
+    ///let a = PersianDateTime::from_persian_str("۱۴۰۳/۰۸/۲۰ ۲۳:۰۷").unwrap();
+    ///assert_eq!(a, PersianDateTime::from_persian_str("1403-8-20T23:07:00").unwrap());
+    ///
+    ///let b = PersianDateTime::from_persian_str("1403/08/20").unwrap();
+    ///assert_eq!(b.to_string(), "1403-08-20 00:00:00 +03:30");
+    /// ```
+    pub fn from_persian_str(s: &str) -> Result<Self, PersianError> {
+        let s: String = s
+            .trim()
+            .chars()
+            .map(|c| {
+                digit_value(c)
+                    .and_then(|d| char::from_digit(d, 10))
+                    .unwrap_or(c)
+            })
+            .collect();
+        let (date, time) = match s.split_once([' ', 'T']) {
+            Some((date, time)) => (date, Some(time.trim_start())),
+            None => (s.as_str(), None),
+        };
+        let (year, month, day) = split_ymd(date)?;
+        let date = PersianDate::from_ymd_opt(year, month, day)
+            .ok_or(PersianError::InvalidJalaliDate { year, month, day })?;
+        let time = match time {
+            Some(time) => NaiveTime::parse_from_str(time, "%H:%M:%S")
+                .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+                .map_err(|_| PersianError::InvalidFormat)?,
+            None => NaiveTime::MIN,
+        };
+        Self::from_local(date, time, Resolve::Earliest).ok_or(PersianError::OutOfRange)
+    }
+}
+
+/// Format many datetimes with the same pattern, the pattern is only parsed once
+/// ```rust
+///use chrono::{DateTime, Duration, Utc};
+///use chrono_persian::{format_many, PersianDateTime};
+///
+///let start = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let rows = [start, start + Duration::days(1)].map(PersianDateTime::from);
+///let a = format_many(&rows, "%Y/%m/%d %H:%M").unwrap();
+///assert_eq!(a, ["1403/08/20 02:08", "1403/08/21 02:08"]);
+/// ```
+pub fn format_many(values: &[PersianDateTime], pattern: &str) -> Result<Vec<String>, PersianError> {
+    let items = compile(pattern)?;
+    let mut out = Vec::with_capacity(values.len());
+    for value in values {
+        let mut s = String::new();
+        write_items(
+            &mut s,
+            &items,
+            value.date(),
+            Some(value.time()),
+            Digits::Latin,
+        )?;
+        out.push(s);
+    }
+    Ok(out)
+}
+
+/// Write many datetimes with the same pattern to `out`, one per line
+///
+/// a single buffer is reused for every line, an invalid pattern fails with
+/// [`io::ErrorKind::InvalidInput`]
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::{write_many, PersianDateTime};
+///
+///let start = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let mut out = Vec::new();
+///write_many(&mut out, &[PersianDateTime::from(start)], "%Y-%m-%d").unwrap();
+///assert_eq!(out, b"1403-08-20\n");
+/// ```
+#[cfg(feature = "std")]
+pub fn write_many<W: io::Write>(
+    out: &mut W,
+    values: &[PersianDateTime],
+    pattern: &str,
+) -> io::Result<()> {
+    let invalid = |e: PersianError| io::Error::new(io::ErrorKind::InvalidInput, e);
+    let items = compile(pattern).map_err(invalid)?;
+    let mut line = String::new();
+    for value in values {
+        line.clear();
+        write_items(
+            &mut line,
+            &items,
+            value.date(),
+            Some(value.time()),
+            Digits::Latin,
+        )
+        .map_err(invalid)?;
+        line.push('\n');
+        out.write_all(line.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// write a date (and time) with the items, fails if the value has no time but the items need it
+pub(crate) fn write_items<W: Write>(
+    out: &mut W,
+    items: &[Item],
+    date: PersianDate,
+    time: Option<NaiveTime>,
+    digits: Digits,
+) -> Result<(), PersianError> {
+    if time.is_none() && items.iter().any(Item::is_time) {
+        return Err(PersianError::InvalidPattern);
+    }
+    write_fields(out, items, date, time.unwrap_or(NaiveTime::MIN), digits)
+        .map_err(|_| PersianError::WriteFailed)
+}
+
+fn write_fields<W: Write>(
+    out: &mut W,
+    items: &[Item],
+    date: PersianDate,
+    time: NaiveTime,
+    digits: Digits,
+) -> fmt::Result {
+    for item in items {
+        let (value, width) = match item {
+            Item::Literal(s) => {
+                out.write_str(s)?;
+                continue;
+            }
+            Item::MonthName => {
+                out.write_str(MONTH_NAMES_FA[date.month() as usize - 1])?;
+                continue;
+            }
+            Item::WeekdayName => {
+                out.write_str(WEEKDAY_NAMES_FA[days_from_saturday(date)])?;
+                continue;
+            }
+            Item::Year => (date.year(), 4),
+            Item::Month => (date.month() as i32, 2),
+            Item::Day => (date.day() as i32, 2),
+            Item::Hour => (time.hour() as i32, 2),
+            Item::Minute => (time.minute() as i32, 2),
+            // chrono keeps a leap second as 59 with a second's worth of extra nanoseconds
+            Item::Second if time.nanosecond() >= 1_000_000_000 => (60, 2),
+            Item::Second => (time.second() as i32, 2),
+        };
+        write_number(out, value, width, digits)?;
+    }
+    Ok(())
+}
+
+fn days_from_saturday(date: PersianDate) -> usize {
+    (date.weekday().num_days_from_monday() as usize + 2) % 7
+}
+
+/// the index of the name `s` starts with, the zero-width non-joiner inside names is optional
+fn read_name(s: &str, names: &[&str]) -> Option<(usize, usize)> {
+    names.iter().enumerate().find_map(|(i, name)| {
+        if let Some(rest) = s.strip_prefix(name) {
+            return Some((i, s.len() - rest.len()));
+        }
+        let joined = name.replace('\u{200c}', "");
+        let rest = s.strip_prefix(joined.as_str())?;
+        Some((i, s.len() - rest.len()))
+    })
+}
+
+/// write a zero padded number with the given digits
+pub(crate) fn write_number<W: Write>(
+    out: &mut W,
+    value: i32,
+    width: usize,
+    digits: Digits,
+) -> fmt::Result {
+    if value < 0 {
+        out.write_char('-')?;
+    }
+    let mut buf = [0u8; 10];
+    let mut n = value.unsigned_abs();
+    let mut len = 0;
+    while n > 0 || len == 0 {
+        buf[len] = (n % 10) as u8;
+        n /= 10;
+        len += 1;
+    }
+    for _ in len..width {
+        out.write_char(digit(0, digits))?;
+    }
+    for &d in buf[..len].iter().rev() {
+        out.write_char(digit(d, digits))?;
+    }
+    Ok(())
+}
+
+fn digit(d: u8, digits: Digits) -> char {
+    match digits {
+        Digits::Latin => (b'0' + d) as char,
+        Digits::Persian => char::from_u32('۰' as u32 + d as u32).unwrap_or('۰'),
+    }
+}
+
+/// the value of a latin, persian or arabic digit
+pub(crate) fn digit_value(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' => c.to_digit(10),
+        '۰'..='۹' => Some(c as u32 - '۰' as u32),
+        '٠'..='٩' => Some(c as u32 - '٠' as u32),
+        _ => None,
+    }
+}
+
+/// parse a string written with the items, digits of any kind are accepted
+pub(crate) fn parse_items(
+    items: &[Item],
+    s: &str,
+) -> Result<(PersianDate, Option<NaiveTime>), PersianError> {
+    let (mut year, mut month, mut day) = (None, None, None);
+    let (mut hour, mut minute, mut second) = (None, None, None);
+    let mut weekday = None;
+    let mut rest = s;
+    for (i, item) in items.iter().enumerate() {
+        match item {
+            Item::Literal(lit) => {
+                rest = rest
+                    .strip_prefix(lit.as_str())
+                    .ok_or(PersianError::InvalidFormat)?;
+                continue;
+            }
+            Item::MonthName | Item::WeekdayName => {
+                let names: &[&str] = match item {
+                    Item::MonthName => &MONTH_NAMES_FA,
+                    _ => &WEEKDAY_NAMES_FA,
+                };
+                let (index, len) = read_name(rest, names).ok_or(PersianError::InvalidFormat)?;
+                rest = &rest[len..];
+                match item {
+                    Item::MonthName => month = Some(index as i32 + 1),
+                    _ => weekday = Some(index),
+                }
+                continue;
+            }
+            _ => {}
+        }
+        // a year followed by another number has exactly 4 digits, otherwise it takes them all
+        let max = match item {
+            Item::Year if items.get(i + 1).is_some_and(Item::is_numeric) => 4,
+            Item::Year => usize::MAX,
+            _ => 2,
+        };
+        let negative = *item == Item::Year && rest.starts_with('-');
+        if negative {
+            rest = &rest[1..];
+        }
+        let (value, len) = read_number(rest, max).ok_or(PersianError::InvalidFormat)?;
+        rest = &rest[len..];
+        let value = if negative { -value } else { value };
+        let slot = match item {
+            Item::Year => &mut year,
+            Item::Month => &mut month,
+            Item::Day => &mut day,
+            Item::Hour => &mut hour,
+            Item::Minute => &mut minute,
+            _ => &mut second,
+        };
+        *slot = Some(value);
+    }
+    if !rest.is_empty() {
+        return Err(PersianError::InvalidFormat);
+    }
+
+    let (Some(year), Some(month), Some(day)) = (year, month, day) else {
+        return Err(PersianError::InvalidPattern);
+    };
+    let (month, day) = (month as u32, day as u32);
+    let date = PersianDate::from_ymd_opt(year, month, day)
+        .ok_or(PersianError::InvalidJalaliDate { year, month, day })?;
+    // like chrono, a day of the week has to agree with the date
+    if weekday.is_some_and(|w| w != days_from_saturday(date)) {
+        return Err(PersianError::InvalidFormat);
+    }
+    let time = if hour.is_some() || minute.is_some() || second.is_some() {
+        let [h, m, s] = [hour, minute, second].map(|v| v.unwrap_or(0) as u32);
+        // like chrono, a leap second is kept as the last second of the minute
+        let time = match s {
+            60 => NaiveTime::from_hms_nano_opt(h, m, 59, 1_000_000_000),
+            s => NaiveTime::from_hms_opt(h, m, s),
+        };
+        Some(time.ok_or(PersianError::InvalidFormat)?)
+    } else {
+        None
+    };
+    Ok((date, time))
+}
+
+/// read at most `max` leading digits, returns the value and the length in bytes
+fn read_number(s: &str, max: usize) -> Option<(i32, usize)> {
+    let mut value: i32 = 0;
+    let mut len = 0;
+    for (count, c) in s.chars().enumerate() {
+        if count == max {
+            break;
+        }
+        let Some(d) = digit_value(c) else {
+            break;
+        };
+        value = value.checked_mul(10)?.checked_add(d as i32)?;
+        len += c.len_utf8();
+    }
+    (len > 0).then_some((value, len))
+}
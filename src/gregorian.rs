@@ -0,0 +1,92 @@
+#[cfg(feature = "std")]
+use chrono::Local;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+use crate::{PersianDate, PersianDateTime};
+
+/// Convert a persian (jalali) date to the gregorian calendar, `None` if it's not a valid date
+/// or out of chrono's range
+/// ```rust
+///use chrono_persian::jalali_to_gregorian;
+///
+///assert_eq!(jalali_to_gregorian(1403, 8, 20).unwrap().to_string(), "2024-11-10");
+///assert_eq!(jalali_to_gregorian(1403, 12, 30).unwrap().to_string(), "2025-03-20");
+///assert!(jalali_to_gregorian(1404, 12, 30).is_none());
+/// ```
+pub fn jalali_to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    PersianDate::from_ymd_opt(year, month, day)?.to_gregorian()
+}
+
+/// Convert a gregorian calendar date to the persian one, `None` if it's not a valid date
+///
+/// this is the civil mode: a date is a day on the calendar, not an instant, so the result never
+/// depends on a timezone or the time of day. the instant-based apis, such as
+/// [`crate::ToPersian`] and [`PersianDateTime::from`], first move the instant to iran's time
+/// which can change the day, [`jalali_to_gregorian`] is the inverse of this one
+/// ```rust
+///use chrono_persian::convert_civil;
+///
+///assert_eq!(convert_civil(2024, 11, 10), Some((1403, 8, 20)));
+///assert_eq!(convert_civil(2025, 3, 21), Some((1404, 1, 1)));
+///assert_eq!(convert_civil(2025, 2, 29), None);
+/// ```
+pub fn convert_civil(year: i32, month: u32, day: u32) -> Option<(i32, u32, u32)> {
+    let date = PersianDate::from(NaiveDate::from_ymd_opt(year, month, day)?);
+    Some((date.year(), date.month(), date.day()))
+}
+
+/// Convert a persian datetime back to a chrono type, the inverse of [`crate::ToPersian`]
+///
+/// converting an instant to persian and back always gives the same instant
+/// ```rust
+///use chrono::{DateTime, Datelike, Duration, Utc};
+///use chrono_persian::{CompiledFormat, FromPersian, PersianDateTime};
+///
+///let entered = CompiledFormat::new("%Y-%m-%d %H:%M:%S").unwrap().parse("1403-08-20 14:30:00").unwrap();
+///let stored = DateTime::<Utc>::from_persian(&entered).unwrap();
+///assert_eq!(stored.to_string(), "2024-11-10 11:00:00 UTC");
+///
+///let mut x = "1900-01-01 00:00:00.25 UTC".parse::<DateTime<Utc>>().unwrap();
+///while x.year() < 2100 {
+///    assert_eq!(PersianDateTime::from(x).to_gregorian::<DateTime<Utc>>(), Some(x));
+///    x += Duration::hours(17);
+///}
+/// ```
+pub trait FromPersian: Sized {
+    /// `None` when the result is out of range
+    fn from_persian(value: &PersianDateTime) -> Option<Self>;
+}
+
+impl FromPersian for DateTime<Utc> {
+    fn from_persian(value: &PersianDateTime) -> Option<Self> {
+        Some(value.to_utc())
+    }
+}
+
+/// keeps the offset of the wall clock
+impl FromPersian for DateTime<FixedOffset> {
+    fn from_persian(value: &PersianDateTime) -> Option<Self> {
+        Some(value.to_fixed_offset())
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromPersian for DateTime<Local> {
+    fn from_persian(value: &PersianDateTime) -> Option<Self> {
+        Some(value.to_utc().with_timezone(&Local))
+    }
+}
+
+/// the instant in utc, like the `NaiveDateTime` impl of [`crate::ToPersian`]
+impl FromPersian for NaiveDateTime {
+    fn from_persian(value: &PersianDateTime) -> Option<Self> {
+        Some(value.to_utc().naive_utc())
+    }
+}
+
+impl PersianDateTime {
+    /// Convert to a chrono type, see [`FromPersian`]
+    pub fn to_gregorian<T: FromPersian>(&self) -> Option<T> {
+        T::from_persian(self)
+    }
+}
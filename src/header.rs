@@ -0,0 +1,56 @@
+//! Date headers for http apis that expose both calendars
+//!
+//! the standard `Date` header is the RFC 7231 gregorian form, and [`JALALI_DATE`] carries the
+//! same instant in iran's time as [`PersianDateTime::to_rfc3339`] writes it, to the second
+//! ```rust
+//!use chrono::{DateTime, Utc};
+//!use chrono_persian::header;
+//!
+//!let now = "2024-11-09 22:38:28.5 UTC".parse::<DateTime<Utc>>().unwrap();
+//!let [date, jalali] = header::date_headers(now);
+//!assert_eq!(date, ("Date", "Sat, 09 Nov 2024 22:38:28 GMT".to_string()));
+//!assert_eq!(jalali, ("X-Jalali-Date", "1403-08-20T02:08:28+03:30".to_string()));
+//!
+//!let parsed = header::parse_jalali_date(&jalali.1).unwrap();
+//!assert_eq!(parsed.to_gregorian::<DateTime<Utc>>().unwrap().timestamp(), now.timestamp());
+//! ```
+
+use alloc::string::{String, ToString};
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{PersianDateTime, PersianError};
+
+/// The name of the persian date header
+pub const JALALI_DATE: &str = "X-Jalali-Date";
+
+/// Write the instant in the RFC 7231 form of the `Date` header, such as
+/// `Sat, 09 Nov 2024 22:38:28 GMT`
+pub fn http_date(instant: DateTime<Utc>) -> String {
+    instant.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Write the instant for the [`JALALI_DATE`] header, such as `1403-08-20T02:08:28+03:30`
+pub fn jalali_date(instant: DateTime<Utc>) -> String {
+    PersianDateTime::from(instant).to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+/// the `Date` and [`JALALI_DATE`] headers of the instant, as name and value pairs
+pub fn date_headers(instant: DateTime<Utc>) -> [(&'static str, String); 2] {
+    [
+        ("Date", http_date(instant)),
+        (JALALI_DATE, jalali_date(instant)),
+    ]
+}
+
+/// Parse the value of a [`JALALI_DATE`] header, surrounding whitespace is ignored
+/// ```rust
+///use chrono_persian::header::parse_jalali_date;
+///use chrono_persian::PersianError;
+///
+///let a = parse_jalali_date(" 1403-08-20T02:08:28+03:30 ").unwrap();
+///assert_eq!(a.to_string(), "1403-08-20 02:08:28 +03:30");
+///assert_eq!(parse_jalali_date("Sat, 09 Nov 2024 22:38:28 GMT"), Err(PersianError::InvalidFormat));
+/// ```
+pub fn parse_jalali_date(value: &str) -> Result<PersianDateTime, PersianError> {
+    PersianDateTime::parse_from_rfc3339(value.trim())
+}
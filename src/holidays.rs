@@ -0,0 +1,267 @@
+//! Iran's official holidays that fall on fixed solar dates
+//!
+//! the religious holidays follow the lunar hijri calendar and move every year, they aren't
+//! included, add them to [`BusinessCalendar::closures`] when they are announced
+//! ```rust
+//!use chrono_persian::holidays;
+//!use chrono_persian::PersianDate;
+//!
+//!let nature_day = PersianDate::from_ymd_opt(1403, 1, 13).unwrap();
+//!assert!(nature_day.is_official_holiday());
+//!assert_eq!(holidays::holiday_on(nature_day).unwrap().name, "روز طبیعت");
+//!
+//! // nowruz 1404 starts on a friday
+//!let nowruz = PersianDate::from_ymd_opt(1404, 1, 1).unwrap();
+//!assert_eq!(nowruz.next_business_day().unwrap().to_string(), "1404-01-05");
+//! ```
+//!
+//! with the `serde` feature a [`HolidayData`] file can extend or replace the embedded set, so
+//! newly declared closures don't wait for a release of the crate. the functions here and the
+//! holiday methods of [`PersianDate`] only know the embedded set, a loaded file is used
+//! through `HolidayData::calendar` and the lookups of `HolidayData`
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use crate::PersianError;
+use crate::{BusinessCalendar, PersianDate, Weekend};
+
+/// An official holiday on the same solar date every year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Holiday {
+    pub month: u32,
+    pub day: u32,
+    /// the farsi name
+    pub name: &'static str,
+    /// the english name
+    pub name_en: &'static str,
+}
+
+const fn holiday(month: u32, day: u32, name: &'static str, name_en: &'static str) -> Holiday {
+    Holiday {
+        month,
+        day,
+        name,
+        name_en,
+    }
+}
+
+/// The official holidays on fixed solar dates, in calendar order
+pub const SOLAR_HOLIDAYS: [Holiday; 10] = [
+    holiday(1, 1, "نوروز", "Nowruz"),
+    holiday(1, 2, "نوروز", "Nowruz"),
+    holiday(1, 3, "نوروز", "Nowruz"),
+    holiday(1, 4, "نوروز", "Nowruz"),
+    holiday(1, 12, "روز جمهوری اسلامی", "Islamic Republic Day"),
+    holiday(1, 13, "روز طبیعت", "Nature Day"),
+    holiday(3, 14, "رحلت امام خمینی", "Death of Khomeini"),
+    holiday(3, 15, "قیام ۱۵ خرداد", "15 Khordad Uprising"),
+    holiday(11, 22, "پیروزی انقلاب اسلامی", "Revolution Day"),
+    holiday(12, 29, "ملی شدن صنعت نفت", "Oil Nationalization Day"),
+];
+
+/// Get the fixed official holiday on `date`, if there is one, only of the [`SOLAR_HOLIDAYS`]
+pub fn holiday_on(date: PersianDate) -> Option<&'static Holiday> {
+    SOLAR_HOLIDAYS
+        .iter()
+        .find(|h| (h.month, h.day) == (date.month(), date.day()))
+}
+
+/// The official calendar: the thursday and friday weekend and the [`SOLAR_HOLIDAYS`]
+pub fn official_calendar() -> BusinessCalendar {
+    BusinessCalendar {
+        weekend: Weekend::THURSDAY_FRIDAY,
+        holidays: SOLAR_HOLIDAYS.iter().map(|h| (h.month, h.day)).collect(),
+        closures: Vec::new(),
+        working_hours: Vec::new(),
+    }
+}
+
+/// Holidays loaded from a data file, such as json (requires the `serde` feature)
+///
+/// the schema, every field is optional:
+/// - `replace`: `true` to drop the embedded [`SOLAR_HOLIDAYS`] first, `false` by default
+/// - `holidays`: yearly holidays, `{ "month": 1, "day": 1, "name": "نوروز" }` with an optional
+///   name
+/// - `closures`: single days, `{ "date": "1403-04-25", "name": "تاسوعا" }` with an optional
+///   name, in any format [`PersianDate`] deserializes from
+/// ```rust
+///use chrono_persian::holidays::HolidayData;
+///use chrono_persian::{PersianDate, PersianError};
+///
+///let json = r#"{
+///    "closures": [
+///        { "date": "1403-04-25", "name": "تاسوعا" },
+///        { "date": "1403-04-26", "name": "عاشورا" }
+///    ]
+///}"#;
+///let data: HolidayData = serde_json::from_str(json).unwrap();
+///let calendar = data.calendar().unwrap();
+///
+///let tasua = PersianDate::from_ymd_opt(1403, 4, 25).unwrap();
+///assert!(!calendar.is_business_day(tasua));
+///assert!(!calendar.is_business_day(PersianDate::from_ymd_opt(1403, 1, 13).unwrap()));
+///
+///assert_eq!(data.holiday_name(tasua), Some("تاسوعا"));
+///assert_eq!(data.holiday_name(PersianDate::from_ymd_opt(1403, 1, 13).unwrap()), Some("روز طبیعت"));
+///
+///let bad: HolidayData = serde_json::from_str(r#"{"replace":true,"holidays":[{"month":7,"day":31}]}"#).unwrap();
+///assert_eq!(bad.calendar(), Err(PersianError::InvalidMonthDay { month: 7, day: 31 }));
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize)]
+pub struct HolidayData {
+    #[serde(default)]
+    pub replace: bool,
+    #[serde(default)]
+    pub holidays: Vec<YearlyHoliday>,
+    #[serde(default)]
+    pub closures: Vec<Closure>,
+}
+
+/// A yearly holiday of a [`HolidayData`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct YearlyHoliday {
+    pub month: u32,
+    pub day: u32,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// A single closed day of a [`HolidayData`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct Closure {
+    pub date: PersianDate,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl HolidayData {
+    /// Add the holidays to `calendar`, or replace its yearly ones when `replace` is set
+    ///
+    /// fails with [`PersianError::InvalidMonthDay`] on a yearly holiday no year has, such as
+    /// 31 mehr, leaving `calendar` unchanged
+    pub fn apply(&self, calendar: &mut BusinessCalendar) -> Result<(), PersianError> {
+        if let Some(h) = self
+            .holidays
+            .iter()
+            .find(|h| !is_yearly_date(h.month, h.day))
+        {
+            return Err(PersianError::InvalidMonthDay {
+                month: h.month,
+                day: h.day,
+            });
+        }
+        if self.replace {
+            calendar.holidays.clear();
+        }
+        for h in &self.holidays {
+            if !calendar.holidays.contains(&(h.month, h.day)) {
+                calendar.holidays.push((h.month, h.day));
+            }
+        }
+        calendar
+            .closures
+            .extend(self.closures.iter().map(|c| c.date));
+        Ok(())
+    }
+
+    /// the [`official_calendar`] with the holidays applied
+    pub fn calendar(&self) -> Result<BusinessCalendar, PersianError> {
+        let mut calendar = official_calendar();
+        self.apply(&mut calendar)?;
+        Ok(calendar)
+    }
+
+    /// whether `date` is a closure or a yearly holiday of the data, or of the [`SOLAR_HOLIDAYS`]
+    /// when they aren't replaced, the weekend aside
+    pub fn is_holiday(&self, date: PersianDate) -> bool {
+        self.closures.iter().any(|c| c.date == date)
+            || self.yearly_on(date).next().is_some()
+            || !self.replace && holiday_on(date).is_some()
+    }
+
+    /// Get the name of the holiday on `date`, like [`holiday_on`] with the data: the name of
+    /// a closure first, then of a yearly holiday, then the farsi one of the [`SOLAR_HOLIDAYS`]
+    /// when they aren't replaced
+    ///
+    /// `None` if the date isn't a holiday, or none of its entries has a name
+    pub fn holiday_name(&self, date: PersianDate) -> Option<&str> {
+        let closure = self
+            .closures
+            .iter()
+            .filter(|c| c.date == date)
+            .find_map(|c| c.name.as_deref());
+        let embedded = || (!self.replace).then(|| holiday_on(date)).flatten();
+        closure
+            .or_else(|| self.yearly_on(date).find_map(|h| h.name.as_deref()))
+            .or_else(|| embedded().map(|h| h.name))
+    }
+
+    /// the yearly holidays of the data on the month and day of `date`
+    fn yearly_on(&self, date: PersianDate) -> impl Iterator<Item = &YearlyHoliday> {
+        self.holidays
+            .iter()
+            .filter(move |h| (h.month, h.day) == (date.month(), date.day()))
+    }
+}
+
+/// whether the day is in the month in some year, 30 esfand only in leap years
+#[cfg(feature = "serde")]
+fn is_yearly_date(month: u32, day: u32) -> bool {
+    match month {
+        1..=6 => (1..=31).contains(&day),
+        7..=12 => (1..=30).contains(&day),
+        _ => false,
+    }
+}
+
+impl BusinessCalendar {
+    /// Count the business days from `start` up to but not including `end`, negative when `end`
+    /// is before `start`
+    /// ```rust
+    ///use chrono_persian::holidays::official_calendar;
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let calendar = official_calendar();
+    ///let start = PersianDate::from_ymd_opt(1403, 12, 25).unwrap();
+    ///let end = PersianDate::from_ymd_opt(1404, 1, 20).unwrap();
+    ///assert_eq!(calendar.business_days_between(start, end), 13);
+    ///assert_eq!(calendar.business_days_between(end, start), -13);
+    /// ```
+    pub fn business_days_between(&self, start: PersianDate, end: PersianDate) -> i64 {
+        let (from, to, sign) = match start <= end {
+            true => (start, end, 1),
+            false => (end, start, -1),
+        };
+        let count = from
+            .iter_days()
+            .take_while(|d| *d < to)
+            .filter(|d| self.is_business_day(*d))
+            .count() as i64;
+        count * sign
+    }
+}
+
+impl PersianDate {
+    /// whether the date is one of the [`SOLAR_HOLIDAYS`], a loaded `HolidayData` isn't
+    /// taken into account
+    pub fn is_official_holiday(&self) -> bool {
+        holiday_on(*self).is_some()
+    }
+
+    /// Get the first business day of the [`official_calendar`] after the date, for a loaded
+    /// `HolidayData` use its `calendar`
+    pub fn next_business_day(&self) -> Option<Self> {
+        official_calendar().next_business_day(*self)
+    }
+
+    /// Count the business days of the [`official_calendar`] from the date up to but not
+    /// including `end`, see [`BusinessCalendar::business_days_between`]
+    pub fn business_days_between(&self, end: PersianDate) -> i64 {
+        official_calendar().business_days_between(*self, end)
+    }
+}
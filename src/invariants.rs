@@ -0,0 +1,249 @@
+//! Invariants every jalali calendar conversion should hold
+//!
+//! the checks take the conversion as closures, so they can be run against wrappers around this
+//! crate or other implementations of the calendar, each one walks the gregorian days of a range
+//! and returns the first violation it finds
+//! ```rust
+//!use chrono::NaiveDate;
+//!use chrono_persian::{invariants, is_valid_jalali, PersianDate};
+//!
+//!let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+//!let days = start..=NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+//!let to_jalali = |d: NaiveDate| {
+//!    let p = PersianDate::from(d);
+//!    (p.year(), p.month(), p.day())
+//!};
+//!let to_gregorian = |y, m, d| PersianDate::from_ymd_opt(y, m, d)?.to_gregorian();
+//!let weekday = |y, m, d| PersianDate::from_ymd_opt(y, m, d).unwrap().weekday();
+//!let month_length = |y, m| (29..=31).rev().find(|&d| is_valid_jalali(y, m, d)).unwrap();
+//!
+//!assert_eq!(invariants::round_trip(days.clone(), to_jalali, to_gregorian), Ok(()));
+//!assert_eq!(invariants::monotonic(days.clone(), to_jalali), Ok(()));
+//!assert_eq!(invariants::weekday_continuity(days.clone(), to_jalali, weekday), Ok(()));
+//!assert_eq!(invariants::month_lengths(days, to_jalali, month_length), Ok(()));
+//! ```
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::PersianDate;
+
+/// A jalali date as `(year, month, day)`
+pub type Ymd = (i32, u32, u32);
+
+/// The first broken invariant a check found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Violation {
+    /// converting `gregorian` to jalali and back didn't give it back
+    RoundTrip {
+        gregorian: NaiveDate,
+        jalali: Ymd,
+        back: Option<NaiveDate>,
+    },
+    /// the day after `gregorian` isn't the day after its jalali date
+    NotMonotonic {
+        gregorian: NaiveDate,
+        jalali: Ymd,
+        next: Ymd,
+    },
+    /// the jalali date of `gregorian` falls on another day of the week
+    Weekday {
+        gregorian: NaiveDate,
+        jalali: Ymd,
+        found: Weekday,
+    },
+    /// a month had a different number of days than its reported length
+    MonthLength {
+        year: i32,
+        month: u32,
+        expected: u32,
+        found: u32,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::RoundTrip {
+                gregorian,
+                jalali: (y, m, d),
+                back,
+            } => match back {
+                Some(back) => write!(
+                    f,
+                    "{gregorian} converts to {y:04}/{m:02}/{d:02} which converts back to {back}"
+                ),
+                None => write!(
+                    f,
+                    "{gregorian} converts to {y:04}/{m:02}/{d:02} which doesn't convert back"
+                ),
+            },
+            Violation::NotMonotonic {
+                gregorian,
+                jalali: (y, m, d),
+                next: (ny, nm, nd),
+            } => write!(
+                f,
+                "{gregorian} converts to {y:04}/{m:02}/{d:02} but the next day to {ny:04}/{nm:02}/{nd:02}"
+            ),
+            Violation::Weekday {
+                gregorian,
+                jalali: (y, m, d),
+                found,
+            } => write!(
+                f,
+                "{y:04}/{m:02}/{d:02} is reported as {found} but {gregorian} is a {}",
+                gregorian.weekday()
+            ),
+            Violation::MonthLength {
+                year,
+                month,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{year:04}/{month:02} has {found} days but its length is reported as {expected}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Violation {}
+
+/// the jalali date after `date`, given whether `date` is the last of its month
+fn successor((y, m, d): Ymd, last_of_month: bool) -> Ymd {
+    match (last_of_month, m) {
+        (false, _) => (y, m, d + 1),
+        (true, 12) => (y + 1, 1, 1),
+        (true, _) => (y, m + 1, 1),
+    }
+}
+
+/// Check converting every day to jalali and back gives the same day
+pub fn round_trip<F, G>(
+    days: RangeInclusive<NaiveDate>,
+    mut to_jalali: F,
+    mut to_gregorian: G,
+) -> Result<(), Violation>
+where
+    F: FnMut(NaiveDate) -> Ymd,
+    G: FnMut(i32, u32, u32) -> Option<NaiveDate>,
+{
+    for gregorian in days.start().iter_days().take_while(|d| d <= days.end()) {
+        let jalali = to_jalali(gregorian);
+        let back = to_gregorian(jalali.0, jalali.1, jalali.2);
+        if back != Some(gregorian) {
+            return Err(Violation::RoundTrip {
+                gregorian,
+                jalali,
+                back,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Check the day after every day converts to the jalali day after it, this means dates only
+/// ever increase and every jalali date in the range is reached
+pub fn monotonic<F>(days: RangeInclusive<NaiveDate>, mut to_jalali: F) -> Result<(), Violation>
+where
+    F: FnMut(NaiveDate) -> Ymd,
+{
+    let mut previous: Option<(NaiveDate, Ymd)> = None;
+    for gregorian in days.start().iter_days().take_while(|d| d <= days.end()) {
+        let jalali = to_jalali(gregorian);
+        if let Some((before, ymd)) = previous {
+            if jalali != successor(ymd, false) && jalali != successor(ymd, true) {
+                return Err(Violation::NotMonotonic {
+                    gregorian: before,
+                    jalali: ymd,
+                    next: jalali,
+                });
+            }
+        }
+        previous = Some((gregorian, jalali));
+    }
+    Ok(())
+}
+
+/// Check every jalali date is reported on the same day of the week as the gregorian day
+pub fn weekday_continuity<F, W>(
+    days: RangeInclusive<NaiveDate>,
+    mut to_jalali: F,
+    mut weekday: W,
+) -> Result<(), Violation>
+where
+    F: FnMut(NaiveDate) -> Ymd,
+    W: FnMut(i32, u32, u32) -> Weekday,
+{
+    for gregorian in days.start().iter_days().take_while(|d| d <= days.end()) {
+        let jalali = to_jalali(gregorian);
+        let found = weekday(jalali.0, jalali.1, jalali.2);
+        if found != gregorian.weekday() {
+            return Err(Violation::Weekday {
+                gregorian,
+                jalali,
+                found,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Check every jalali month fully inside the range has as many days as its reported length
+pub fn month_lengths<F, L>(
+    days: RangeInclusive<NaiveDate>,
+    mut to_jalali: F,
+    mut month_length: L,
+) -> Result<(), Violation>
+where
+    F: FnMut(NaiveDate) -> Ymd,
+    L: FnMut(i32, u32) -> u32,
+{
+    // (year, month, days seen), only counted for months whose first day is in the range
+    let mut current: Option<(i32, u32, u32)> = None;
+    for gregorian in days.start().iter_days().take_while(|d| d <= days.end()) {
+        let (y, m, d) = to_jalali(gregorian);
+        match current {
+            Some((cy, cm, seen)) if (cy, cm) == (y, m) => current = Some((cy, cm, seen + 1)),
+            Some((year, month, found)) => {
+                let expected = month_length(year, month);
+                if found != expected {
+                    return Err(Violation::MonthLength {
+                        year,
+                        month,
+                        expected,
+                        found,
+                    });
+                }
+                current = Some((y, m, 1));
+            }
+            None if d == 1 => current = Some((y, m, 1)),
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+/// Check this crate's conversion is [`monotonic`] over the jalali `years`, clamped to the
+/// range of chrono's dates, meant to be run in ci to catch regressions
+/// ```rust
+///use chrono_persian::invariants::verify_monotonic;
+///
+///assert_eq!(verify_monotonic(1300..=1500), Ok(()));
+/// ```
+pub fn verify_monotonic(years: RangeInclusive<i32>) -> Result<(), Violation> {
+    let first = PersianDate::from_ymd_opt(*years.start(), 1, 1)
+        .and_then(PersianDate::to_gregorian)
+        .unwrap_or(NaiveDate::MIN);
+    let last = PersianDate::from_ymd_opt(years.end().saturating_add(1), 1, 1)
+        .and_then(PersianDate::to_gregorian)
+        .and_then(|d| d.pred_opt())
+        .unwrap_or(NaiveDate::MAX);
+    monotonic(first..=last, |d| {
+        let p = PersianDate::from(d);
+        (p.year(), p.month(), p.day())
+    })
+}
@@ -0,0 +1,184 @@
+use alloc::{string::String, vec::Vec};
+use chrono::{DateTime, Utc};
+
+use crate::{template, DayCache, IntoInstant, PersianDateTime, PersianError};
+
+/// An item [`PersianIteratorExt::map_persian`] can convert
+pub trait MapPersianItem {
+    type Output;
+
+    #[doc(hidden)]
+    fn map_persian(self, cache: &mut DayCache) -> Self::Output;
+}
+
+impl MapPersianItem for DateTime<Utc> {
+    type Output = PersianDateTime;
+
+    fn map_persian(self, cache: &mut DayCache) -> PersianDateTime {
+        cache.convert(&self)
+    }
+}
+
+impl MapPersianItem for &DateTime<Utc> {
+    type Output = PersianDateTime;
+
+    fn map_persian(self, cache: &mut DayCache) -> PersianDateTime {
+        cache.convert(self)
+    }
+}
+
+/// unix timestamps in seconds, `None` if out of range
+impl MapPersianItem for i64 {
+    type Output = Option<PersianDateTime>;
+
+    fn map_persian(self, cache: &mut DayCache) -> Option<PersianDateTime> {
+        cache.convert_timestamp(self)
+    }
+}
+
+/// Iterator returned by [`PersianIteratorExt::map_persian`]
+#[derive(Debug, Clone)]
+pub struct MapPersian<I> {
+    iter: I,
+    cache: DayCache,
+}
+
+impl<I> Iterator for MapPersian<I>
+where
+    I: Iterator,
+    I::Item: MapPersianItem,
+{
+    type Item = <I::Item as MapPersianItem>::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.map_persian(&mut self.cache))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Convert a slice of instants at once, such as a column of an analytics export
+///
+/// the persian date of the last day is reused, so sorted or clustered instants only do the
+/// calendar math once a day, see `benches/batch.rs` for the difference with converting each
+/// instant on its own
+/// ```rust
+///use chrono::{DateTime, Duration, Utc};
+///use chrono_persian::{to_persian_batch, PersianDateTime};
+///
+///let start = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let instants: Vec<_> = (0..1000).map(|i| start + Duration::minutes(i * 7)).collect();
+///let dates = to_persian_batch(&instants);
+///assert_eq!(dates[0].to_string(), "1403-08-20 02:08:28 +03:30");
+///assert!(dates.iter().zip(&instants).all(|(p, i)| *p == PersianDateTime::from(*i)));
+/// ```
+pub fn to_persian_batch(instants: &[DateTime<Utc>]) -> Vec<PersianDateTime> {
+    instants.iter().map_persian().collect()
+}
+
+/// Lazily convert a stream of `DateTime<Utc>`s or unix timestamps to [`PersianDateTime`]s
+pub trait PersianIteratorExt: Iterator + Sized
+where
+    Self::Item: MapPersianItem,
+{
+    /// the last converted day is cached across items, so streams of timestamps from the
+    /// same day (such as logs) don't repeat the calendar math for every item
+    /// ```rust
+    ///use chrono_persian::PersianIteratorExt;
+    ///
+    ///let stamps = [1731191908_i64, 1731191968, i64::MAX];
+    ///let dates: Vec<_> = stamps
+    ///    .into_iter()
+    ///    .map_persian()
+    ///    .map(|p| p.map(|p| p.to_string()))
+    ///    .collect();
+    ///
+    ///assert_eq!(dates[0].as_deref(), Some("1403-08-20 02:08:28 +03:30"));
+    ///assert_eq!(dates[1].as_deref(), Some("1403-08-20 02:09:28 +03:30"));
+    ///assert_eq!(dates[2], None);
+    /// ```
+    fn map_persian(self) -> MapPersian<Self> {
+        MapPersian {
+            iter: self,
+            cache: DayCache::default(),
+        }
+    }
+
+    /// like [`PersianIteratorExt::map_persian`] but with a larger cache,
+    /// for streams that jump between a few days
+    fn map_persian_cached(self, cache: DayCache) -> MapPersian<Self> {
+        MapPersian { iter: self, cache }
+    }
+}
+
+impl<I> PersianIteratorExt for I
+where
+    I: Iterator,
+    I::Item: MapPersianItem,
+{
+}
+
+/// A row [`convert_all`] can convert, any [`IntoInstant`] or a string
+/// [`template::parse_value`] reads as [`template::Calendar::Gregorian`]
+pub trait ConvertRow {
+    fn convert_row(self) -> Result<PersianDateTime, PersianError>;
+}
+
+/// fails with [`PersianError::OutOfRange`]
+impl<T: IntoInstant> ConvertRow for T {
+    fn convert_row(self) -> Result<PersianDateTime, PersianError> {
+        self.into_instant()
+            .map(PersianDateTime::from)
+            .ok_or(PersianError::OutOfRange)
+    }
+}
+
+impl ConvertRow for &str {
+    fn convert_row(self) -> Result<PersianDateTime, PersianError> {
+        template::parse_value(self, template::Calendar::Gregorian)
+    }
+}
+
+impl ConvertRow for &String {
+    fn convert_row(self) -> Result<PersianDateTime, PersianError> {
+        template::parse_value(self, template::Calendar::Gregorian)
+    }
+}
+
+impl ConvertRow for String {
+    fn convert_row(self) -> Result<PersianDateTime, PersianError> {
+        template::parse_value(&self, template::Calendar::Gregorian)
+    }
+}
+
+/// Convert every row that can be, with the index and error of the ones that can't, so a job
+/// can carry on past bad rows and report them
+/// ```rust
+///use chrono_persian::{convert_all, PersianError};
+///
+///let rows = ["2024-11-09T22:38:28Z", "yesterday", "2024-11-10T02:08:28+03:30", "1731191908"];
+///let (dates, failed) = convert_all(rows);
+///assert_eq!(dates.len(), 3);
+///assert!(dates.iter().all(|d| d.to_string() == "1403-08-20 02:08:28 +03:30"));
+///assert_eq!(failed, [(1, PersianError::InvalidFormat)]);
+///
+///let (_, failed) = convert_all([0_i64, i64::MAX]);
+///assert_eq!(failed, [(1, PersianError::OutOfRange)]);
+/// ```
+pub fn convert_all<I>(rows: I) -> (Vec<PersianDateTime>, Vec<(usize, PersianError)>)
+where
+    I: IntoIterator,
+    I::Item: ConvertRow,
+{
+    let mut converted = Vec::new();
+    let mut failed = Vec::new();
+    for (index, row) in rows.into_iter().enumerate() {
+        match row.convert_row() {
+            Ok(date) => converted.push(date),
+            Err(e) => failed.push((index, e)),
+        }
+    }
+    (converted, failed)
+}
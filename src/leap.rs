@@ -0,0 +1,81 @@
+//! Leap years under the different algorithms of the persian calendar
+//!
+//! the official calendar is astronomical, arithmetic algorithms only approximate it and
+//! disagree with it (and with each other) for some years. even the astronomical rule is
+//! contested when the equinox is close to noon, nowruz 1503 is predicted at 12:04 in tehran
+//! which a few minutes of error in the estimated earth rotation would move before noon,
+//! making 1502 a common year instead of a leap year
+
+#[cfg(feature = "astro")]
+use chrono::FixedOffset;
+
+use crate::calendar;
+
+/// An algorithm deciding which persian years are leap years
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// the 33-year cycle of <https://jdf.scr.ir>, defined for every year
+    ThirtyThreeYear,
+    /// the algorithm by kazimierz m. borkowski (as used by jalaali-js), defined for years
+    /// -61 to 3177
+    Borkowski,
+    /// nowruz is the day of the march equinox if it happens before noon in tehran, the day
+    /// after otherwise, defined for years 379 to 2378 (requires the `astro` feature)
+    #[cfg(feature = "astro")]
+    Astronomical,
+}
+
+/// The algorithm this crate converts dates with
+///
+/// it is pinned, changing it is a breaking change, so long-lived systems (such as ones
+/// computing maturities decades ahead) can assert it and fail to build on a surprise
+/// ```rust
+///use chrono_persian::{Algorithm, CALENDAR_ALGORITHM};
+///
+///const _: () = assert!(matches!(CALENDAR_ALGORITHM, Algorithm::ThirtyThreeYear));
+/// ```
+pub const CALENDAR_ALGORITHM: Algorithm = Algorithm::ThirtyThreeYear;
+
+/// Whether a persian year is a leap year under `algorithm`, `None` if the algorithm isn't
+/// defined for the year
+/// ```rust
+///use chrono_persian::{leap_status, Algorithm};
+///
+///assert_eq!(leap_status(1403, Algorithm::ThirtyThreeYear), Some(true));
+///assert_eq!(leap_status(1403, Algorithm::Borkowski), Some(true));
+///assert_eq!(leap_status(4000, Algorithm::Borkowski), None);
+///
+/// // the arithmetic algorithms drift apart after 1634
+///assert_eq!(leap_status(1635, Algorithm::ThirtyThreeYear), Some(false));
+///assert_eq!(leap_status(1635, Algorithm::Borkowski), Some(true));
+/// ```
+pub fn leap_status(year: i32, algorithm: Algorithm) -> Option<bool> {
+    match algorithm {
+        Algorithm::ThirtyThreeYear => Some(calendar::is_leap_year(year)),
+        Algorithm::Borkowski => {
+            let (first, last) = calendar::borkowski::RANGE;
+            (first..last)
+                .contains(&year)
+                .then(|| calendar::borkowski::is_leap_year(year))
+        }
+        #[cfg(feature = "astro")]
+        Algorithm::Astronomical => {
+            let days = astronomical_nowruz(year + 1)? - astronomical_nowruz(year)?;
+            Some(days.num_days() == 366)
+        }
+    }
+}
+
+/// the gregorian date of nowruz by the noon rule, noon is taken at tehran's meridian (+03:30)
+#[cfg(feature = "astro")]
+fn astronomical_nowruz(year: i32) -> Option<chrono::NaiveDate> {
+    let tehran = FixedOffset::east_opt(3 * 3600 + 1800)?;
+    let tahvil = crate::astro::nowruz(year)?.with_timezone(&tehran);
+    let date = tahvil.date_naive();
+    if tahvil.time() < chrono::NaiveTime::from_hms_opt(12, 0, 0)? {
+        Some(date)
+    } else {
+        date.succ_opt()
+    }
+}
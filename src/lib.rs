@@ -1,7 +1,8 @@
 //! # chrono-persian
 //! ## About
 //! this crate contains a set of helper functions to convert chrono datetimes to persian (jalali) calender
-//! it provides a simple Trait `ToPersian` which is implemented for `NaiveDateTime`, `DateTime<Utc>` and `DateTime<Local`
+//! it provides a simple Trait `ToPersian` which is implemented for `NaiveDateTime`, `DateTime<Utc>` and `DateTime<Local>`,
+//! and for `NaiveDate` and `DateTime<FixedOffset>` which convert to [`PersianDate`] and [`PersianDateTime`]
 //! ##
 //!
 //! ## Example
@@ -25,28 +26,260 @@
 //!assert_eq!(a.to_string(),"1403-09-06 12:25:11");
 //!
 //! ```
+//! the chrono types returned by `ToPersian` hold jalali fields in a gregorian type, so chrono's
+//! arithmetic on them is meaningless, [`PersianDate`] and [`PersianDateTime`] keep the two apart
+//! ```
+//!use chrono::{DateTime, Duration, Utc};
+//!use chrono_persian::PersianDateTime;
+//!
+//!let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+//!let a = PersianDateTime::from(utc) + Duration::days(11);
+//!assert_eq!((a.year(), a.month(), a.day()), (1403, 9, 1));
+//!assert_eq!(a.to_utc(), utc + Duration::days(11));
+//! ```
+//! dates without a time, [`convert_civil`] and `PersianDate::from(NaiveDate)`, convert the day on
+//! the calendar and never look at a timezone, only instants are moved to iran's time first
+//!
+//! the calendar arithmetic alone, as `const fn`s without `std` or chrono, is the
+//! `chrono-persian-core` crate, for firmware and wasm. without the default `std` feature this
+//! crate is `no_std` too, it only needs `alloc`, and leaves out the system clock, `SystemTime`,
+//! io and the per-thread caches
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(step_trait))]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use chrono::Local;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
 
-use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
-use std::ops::Deref;
-use std::sync::LazyLock;
+mod animal;
+mod anniversary;
+mod any;
+#[cfg(feature = "astro")]
+pub mod astro;
+mod attributes;
+#[cfg(feature = "audit")]
+pub mod audit;
+mod business;
+mod bytes;
+mod cache;
+mod calendar;
+mod check;
+#[cfg(feature = "clap")]
+mod cli;
+mod clock;
+mod corpus;
+mod counts;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod date;
+mod datetime;
+mod daycount;
+mod days;
+mod deadline;
+#[cfg(feature = "derive")]
+pub mod display;
+mod encoding;
+mod error;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+pub mod extract;
+mod facts;
+#[cfg(feature = "derive")]
+mod field;
+mod filename;
+mod format;
+mod gregorian;
+pub mod header;
+pub mod holidays;
+pub mod invariants;
+mod iter;
+mod leap;
+pub mod lint;
+#[cfg(feature = "locale")]
+pub mod locale;
+mod mask;
+mod meeting;
+pub mod migrate;
+mod month;
+mod naive;
+mod names;
+mod overlap;
+mod payroll;
+mod period;
+mod plain;
+mod prorate;
+mod quarter;
+#[cfg(feature = "rand")]
+mod random;
+mod range;
+mod reconstruct;
+mod relative;
+mod resolve;
+mod round;
+mod scheme;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod signal;
+mod sla;
+mod small;
+mod span;
+pub mod template;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+mod trading;
+#[cfg(feature = "tz")]
+pub mod tz;
+mod valid;
+#[cfg(feature = "validator")]
+pub mod validate;
+mod week;
+mod weekend;
+mod zone;
 
-static LOCAL: LazyLock<Local> = LazyLock::new(|| Local::from_offset(&FIXED_OFFSET));
+pub use animal::{animal_of_year, Animal};
+pub use anniversary::{matches_anniversary, same_month_day, LeapDayPolicy};
+pub use any::{to_persian_any, to_persian_date_any, CalendarInstant, IntoInstant};
+pub use attributes::TimeAttributes;
+pub use business::{due_date, working_days_in_month, BusinessCalendar, Roll};
+pub use cache::DayCache;
+pub use check::{self_check, SelfCheckFailure};
+pub use chrono_persian_core::{persian_format, Fields, FormatFields};
+#[cfg(feature = "derive")]
+pub use chrono_persian_derive::{persian_serde, PersianDisplay};
+#[cfg(feature = "clap")]
+pub use cli::PersianDateParser;
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use clock::{Clock, FixedClock};
+pub use corpus::{fuzz_corpus, CorpusEntry};
+pub use counts::{count_by_month, count_by_weekday, MonthCounts, WeekdayCounts};
+pub use date::PersianDate;
+pub use datetime::{persian_from_unix_timestamp, render_in_zones, PersianDateTime};
+pub use daycount::{day_count, DayCountConvention};
+pub use days::{PersianDateDaysIterator, PersianMonthsIterator, PersianRangeExt, RangeDays};
+pub use deadline::{
+    end_of_persian, end_of_persian_day, end_of_persian_month, end_of_persian_quarter,
+    end_of_persian_week, end_of_persian_year, month_boundaries, resample_boundaries,
+    start_of_persian,
+};
+pub use encoding::{
+    decode_day_numbers, encode_day_numbers, DAY_NUMBER_EPOCH, ENCODING_VERSION,
+    UNIX_EPOCH_DAY_NUMBER,
+};
+pub use error::PersianError;
+pub use facts::{
+    days_in_jalali_month, days_in_jalali_year, is_jalali_leap_year, jalali_ordinal,
+    jalali_week_of_year, nowruz_weekday, year_facts, YearFacts,
+};
+#[cfg(feature = "derive")]
+pub use field::PersianField;
+pub use filename::FilenamePrecision;
+#[cfg(feature = "std")]
+pub use format::write_many;
+pub use format::{format_many, CompiledFormat, Digits, Rounding};
+pub use gregorian::{convert_civil, jalali_to_gregorian, FromPersian};
+pub use iter::{
+    convert_all, to_persian_batch, ConvertRow, MapPersian, MapPersianItem, PersianIteratorExt,
+};
+pub use leap::{leap_status, Algorithm, CALENDAR_ALGORITHM};
+pub use mask::{InputMask, MaskState};
+pub use meeting::{meeting_slots, WorkingHours};
+pub use month::{MonthDays, PersianMonthOfYear};
+pub use naive::NaiveDateTimeExt;
+pub use names::{MONTH_NAMES_EN, MONTH_NAMES_FA, WEEKDAY_NAMES_EN, WEEKDAY_NAMES_FA};
+pub use overlap::{gregorian_day_to_jalali_span, JalaliDaySpan};
+pub use payroll::{payroll_months, payroll_period, PayrollDays, PayrollMonth};
+pub use period::PersianPeriod;
+pub use plain::{to_persian_fields, JalaliFields};
+pub use prorate::MonthSegment;
+pub use quarter::PersianQuarter;
+#[cfg(feature = "rand")]
+pub use random::random_date_in;
+pub use range::PersianDateRange;
+pub use reconstruct::PartialDate;
+pub use relative::{describe_difference, Language, RelativeFormat, RelativeUnit};
+pub use resolve::Resolve;
+pub use round::{PersianDurationRound, PersianUnit};
+pub use scheme::{PeriodScheme, SchemePeriod};
+pub use signal::PersianDateSignal;
+pub use sla::{business_hours_between, sla_deadline};
+pub use small::SmallStr;
+pub use span::{CalendarDuration, PersianDays, PersianMonths, PersianSpan, PersianYears};
+pub use trading::TradingCalendar;
+pub use valid::{is_valid_gregorian, is_valid_jalali};
+pub use week::PersianIsoWeek;
+pub use weekend::Weekend;
+pub use zone::ToPersianInZone;
 
-/// Iran's offset, already tested, so its safe to unwrap
-static FIXED_OFFSET: LazyLock<FixedOffset> =
-    LazyLock::new(|| unsafe { FixedOffset::east_opt(3 * 3600 + 1800).unwrap_unchecked() });
+/// Iran's offset
+#[cfg(not(feature = "tz"))]
+const FIXED_OFFSET: FixedOffset = FixedOffset::east_opt(3 * 3600 + 1800).expect("in range");
 
-static ZERO_OFFSET: LazyLock<FixedOffset> =
-    LazyLock::new(|| unsafe { FixedOffset::east_opt(0).unwrap_unchecked() });
+#[cfg(feature = "std")]
+const ZERO_OFFSET: FixedOffset = FixedOffset::east_opt(0).expect("in range");
 
 /// Convert a chrono type to the persian equivalent
 pub trait ToPersian {
-    fn to_persian(&self) -> Option<Self>
-    where
-        Self: Sized;
+    /// the type holding the converted value, for chrono types this is the type itself
+    type Output;
+
+    fn to_persian(&self) -> Option<Self::Output>;
+
+    /// Convert like [`ToPersian::to_persian`], with [`PersianError::OutOfRange`] when the
+    /// result can't be represented
+    /// ```rust
+    ///use chrono::{NaiveDate, NaiveDateTime};
+    ///use chrono_persian::{PersianError, ToPersian};
+    ///
+    ///let date = NaiveDate::from_ymd_opt(2024, 11, 10).unwrap();
+    ///assert_eq!(date.try_to_persian().unwrap().to_string(), "1403-08-20");
+    ///
+    /// // the jalali fields are held in a gregorian type, whose range starts later
+    ///assert_eq!(NaiveDateTime::MIN.try_to_persian(), Err(PersianError::OutOfRange));
+    /// ```
+    fn try_to_persian(&self) -> Result<Self::Output, PersianError> {
+        self.to_persian().ok_or(PersianError::OutOfRange)
+    }
+}
+
+impl<T: ToPersian + ?Sized> ToPersian for &T {
+    type Output = T::Output;
+
+    fn to_persian(&self) -> Option<T::Output> {
+        (**self).to_persian()
+    }
+}
+
+impl<T: ToPersian> ToPersian for Option<T> {
+    type Output = Option<T::Output>;
+
+    /// Convert an optional value, `None` stays `None` and only a failed conversion returns `None`
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::ToPersian;
+    ///
+    ///let deleted_at: Option<DateTime<Utc>> = None;
+    ///assert_eq!(deleted_at.to_persian(), Some(None));
+    ///
+    ///let created_at = Some("2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap());
+    ///let a = created_at.to_persian().unwrap().unwrap();
+    ///assert_eq!(a.to_string(), "1403-08-20 02:08:28 UTC");
+    /// ```
+    fn to_persian(&self) -> Option<Self::Output> {
+        match self {
+            Some(value) => value.to_persian().map(Some),
+            None => Some(None),
+        }
+    }
 }
 
 impl ToPersian for DateTime<Utc> {
+    type Output = Self;
+
     /// Convert a `DateTime<Utc>` to the persian equivalent
     /// ```rust
     ///use chrono::{DateTime, Utc};
@@ -57,13 +290,16 @@ impl ToPersian for DateTime<Utc> {
     ///assert_eq!(a.to_string(), "1403-08-20 02:08:28 UTC");
     /// ```
     fn to_persian(&self) -> Option<Self> {
-        let now = self.with_timezone(LOCAL.deref());
-        let (y, m, d) = gregorian_to_jalali(now.year(), now.month(), now.day());
+        let now = to_tehran(self);
+        let (y, m, d) = to_jalali(&now);
         Some(NaiveDateTime::new(NaiveDate::from_ymd_opt(y, m, d)?, now.time()).and_utc())
     }
 }
 
+#[cfg(feature = "std")]
 impl ToPersian for DateTime<Local> {
+    type Output = Self;
+
     /// Convert a `DateTime<Local>` to the persian equivalent
     /// ```rust
     ///use chrono::{DateTime, Local};
@@ -74,18 +310,22 @@ impl ToPersian for DateTime<Local> {
     ///assert_eq!(b.to_string(), "1403-08-20 02:17:54 +00:00");
     /// ```
     fn to_persian(&self) -> Option<Self> {
-        let now = self.with_timezone(LOCAL.deref());
-        let (y, m, d) = gregorian_to_jalali(now.year(), now.month(), now.day());
+        let now = to_tehran(self);
+        let (y, m, d) = to_jalali(&now);
         let a = NaiveDateTime::new(NaiveDate::from_ymd_opt(y, m, d)?, now.time());
-        Some(DateTime::<Local>::from_naive_utc_and_offset(
-            a,
-            *ZERO_OFFSET,
-        ))
+        Some(DateTime::<Local>::from_naive_utc_and_offset(a, ZERO_OFFSET))
     }
 }
 
 impl ToPersian for NaiveDateTime {
-    /// Convert a `NaiveDateTime` to the persian equivalent
+    type Output = Self;
+
+    /// Convert a `NaiveDateTime` to the persian equivalent, the value is read as utc and the
+    /// result is iran's wall clock
+    ///
+    /// which zone the value is in is easy to get wrong, prefer
+    /// [`NaiveDateTimeExt::to_persian_assuming_utc`] or
+    /// [`NaiveDateTimeExt::to_persian_assuming_tehran`]
     /// ```rust
     ///use chrono::NaiveDateTime;
     ///use chrono_persian::ToPersian;
@@ -95,8 +335,8 @@ impl ToPersian for NaiveDateTime {
     ///assert_eq!(a.to_string(),"1403-09-06 12:25:11");
     /// ```
     fn to_persian(&self) -> Option<Self> {
-        let now = DateTime::<Local>::from_naive_utc_and_offset(*self, *FIXED_OFFSET);
-        let (y, m, d) = gregorian_to_jalali(now.year(), now.month(), now.day());
+        let now = DateTime::<FixedOffset>::from_naive_utc_and_offset(*self, tehran_offset(self));
+        let (y, m, d) = to_jalali(&now);
         Some(NaiveDateTime::new(
             NaiveDate::from_ymd_opt(y, m, d)?,
             now.time(),
@@ -104,37 +344,84 @@ impl ToPersian for NaiveDateTime {
     }
 }
 
-/// source: https://jdf.scr.ir
-fn gregorian_to_jalali(gy: i32, gm: u32, gd: u32) -> (i32, u32, u32) {
-    const G_D_M: [i32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
-    let gy2 = if gm > 2 { gy + 1 } else { gy };
+impl ToPersian for NaiveDate {
+    type Output = PersianDate;
 
-    let mut days = 355666 + (365 * gy) + ((gy2 + 3) / 4) - ((gy2 + 99) / 100)
-        + ((gy2 + 399) / 400)
-        + gd as i32
-        + G_D_M[(gm - 1) as usize];
+    /// Convert a calendar date, it has no time so no time zone is involved
+    /// ```rust
+    ///use chrono::NaiveDate;
+    ///use chrono_persian::ToPersian;
+    ///
+    ///let date = NaiveDate::from_ymd_opt(2024, 11, 10).unwrap();
+    ///assert_eq!(date.to_persian().unwrap().to_string(), "1403-08-20");
+    /// ```
+    fn to_persian(&self) -> Option<PersianDate> {
+        Some(PersianDate::from(*self))
+    }
+}
 
-    let mut jy = -1595 + (33 * (days / 12053));
-    days %= 12053;
-    jy += 4 * (days / 1461);
-    days %= 1461;
+impl ToPersian for DateTime<FixedOffset> {
+    type Output = PersianDateTime;
 
-    if days > 365 {
-        jy += (days - 1) / 365;
-        days = (days - 1) % 365;
+    /// Convert keeping the offset, the persian date is the one of the wall clock at that offset
+    /// ```rust
+    ///use chrono::{DateTime, FixedOffset};
+    ///use chrono_persian::ToPersian;
+    ///
+    ///let kabul = "2024-11-09 23:45:00 +04:30".parse::<DateTime<FixedOffset>>().unwrap();
+    ///let a = kabul.to_persian().unwrap();
+    ///assert_eq!(a.to_string(), "1403-08-19 23:45:00 +04:30");
+    ///assert_eq!(a.to_fixed_offset(), kabul);
+    /// ```
+    fn to_persian(&self) -> Option<PersianDateTime> {
+        Some(PersianDateTime::from(*self).with_offset(*self.offset()))
     }
+}
 
-    let jm = if days < 186 {
-        1 + (days / 31)
-    } else {
-        7 + ((days - 186) / 30)
-    };
+#[cfg(feature = "tz")]
+impl ToPersian for DateTime<chrono_tz::Tz> {
+    type Output = PersianDateTime;
 
-    let jd = if days < 186 {
-        1 + (days % 31)
-    } else {
-        1 + ((days - 186) % 30)
-    };
+    /// Convert keeping the zone's offset at that instant, like the `DateTime<FixedOffset>` impl
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::ToPersian;
+    ///
+    ///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let berlin = utc.with_timezone(&chrono_tz::Europe::Berlin);
+    ///assert_eq!(berlin.to_persian().unwrap().to_string(), "1403-08-19 23:38:28 +01:00");
+    /// ```
+    fn to_persian(&self) -> Option<PersianDateTime> {
+        self.fixed_offset().to_persian()
+    }
+}
+
+/// the first instant of a gregorian calendar day in iran
+fn tehran_midnight(date: NaiveDate) -> Option<DateTime<FixedOffset>> {
+    resolve::tehran_local(date.and_hms_opt(0, 0, 0)?, Resolve::Earliest)
+}
+
+/// iran's offset at an instant, always +03:30 unless the `tz` feature is enabled
+#[cfg(not(feature = "tz"))]
+fn tehran_offset(_utc: &NaiveDateTime) -> FixedOffset {
+    FIXED_OFFSET
+}
+
+#[cfg(feature = "tz")]
+fn tehran_offset(utc: &NaiveDateTime) -> FixedOffset {
+    tz::offset_at_naive(utc)
+}
+
+/// an instant in iran's time
+fn to_tehran<Tz: TimeZone>(instant: &DateTime<Tz>) -> DateTime<FixedOffset> {
+    instant.with_timezone(&tehran_offset(&instant.naive_utc()))
+}
 
-    (jy, jm as u32, jd as u32)
+/// convert the calendar date of `date`, double checking it when the `audit` feature is enabled
+fn to_jalali(date: &impl Datelike) -> (i32, u32, u32) {
+    let gregorian = (date.year(), date.month(), date.day());
+    let jalali = calendar::gregorian_to_jalali(gregorian.0, gregorian.1, gregorian.2);
+    #[cfg(feature = "audit")]
+    audit::check(gregorian, jalali);
+    jalali
 }
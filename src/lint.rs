@@ -0,0 +1,135 @@
+//! Opt-in plausibility checks for dates coming out of imports and ETL pipelines
+//!
+//! unlike parsing, linting never rejects a value on its own, it reports everything that looks off
+//! so the pipeline can decide what to do with it
+//! ```rust
+//!use chrono_persian::lint::{Lint, Plausibility};
+//!
+//!let linter = Plausibility::new(1403);
+//!assert!(linter.check(1403, 8, 20).is_empty());
+//!assert_eq!(linter.check(1403, 7, 31), vec![Lint::DayOutOfRange { month: 7, day: 31, max: 30 }]);
+//!
+//!let lints = linter.check_str("2024/11/10").unwrap();
+//!assert_eq!(lints[0].to_string(), "year 2024 looks gregorian, did you mean 1403/08/20?");
+//! ```
+
+use alloc::vec::Vec;
+use chrono::NaiveDate;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::date::split_ymd;
+use crate::{calendar, PersianDate, PersianError};
+#[cfg(feature = "std")]
+use crate::{Clock, SystemClock};
+
+/// gregorian years that are unlikely to be meant as jalali
+const GREGORIAN_YEARS: RangeInclusive<i32> = 1900..=2199;
+
+/// A single finding about a date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Lint {
+    /// the month isn't between 1 and 12
+    MonthOutOfRange { month: u32 },
+    /// the month doesn't have that day, e.g. day 31 in months 7 to 12
+    DayOutOfRange { month: u32, day: u32, max: u32 },
+    /// the year is further than [`Plausibility::max_years`] from the reference year
+    FarFromPresent { year: i32, reference: i32 },
+    /// the year is a recent gregorian one, `suggestion` is the jalali date if the
+    /// components form a valid gregorian date
+    LooksGregorian {
+        year: i32,
+        suggestion: Option<PersianDate>,
+    },
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lint::MonthOutOfRange { month } => write!(f, "month {month} is out of range"),
+            Lint::DayOutOfRange { month, day, max } => {
+                write!(f, "month {month} has {max} days, got day {day}")
+            }
+            Lint::FarFromPresent { year, reference } => {
+                write!(f, "year {year} is far from {reference}")
+            }
+            Lint::LooksGregorian {
+                year,
+                suggestion: Some(date),
+            } => write!(
+                f,
+                "year {year} looks gregorian, did you mean {:04}/{:02}/{:02}?",
+                date.year(),
+                date.month(),
+                date.day()
+            ),
+            Lint::LooksGregorian {
+                year,
+                suggestion: None,
+            } => write!(f, "year {year} looks gregorian"),
+        }
+    }
+}
+
+/// The thresholds used to lint dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Plausibility {
+    /// the year dates are compared against, usually the current one
+    pub reference_year: i32,
+    /// how many years away from the reference a date can be, `100` by default
+    pub max_years: u32,
+}
+
+#[cfg(feature = "std")]
+impl Default for Plausibility {
+    /// lint against the current year in iran
+    fn default() -> Self {
+        Self::new(SystemClock.today_in_tehran().year())
+    }
+}
+
+impl Plausibility {
+    /// lint against the given jalali year
+    pub fn new(reference_year: i32) -> Self {
+        Self {
+            reference_year,
+            max_years: 100,
+        }
+    }
+
+    /// Check the components of a jalali date, an empty list means the date looks plausible
+    pub fn check(&self, year: i32, month: u32, day: u32) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        if !(1..=12).contains(&month) {
+            lints.push(Lint::MonthOutOfRange { month });
+        } else {
+            let max = calendar::days_in_month(year, month);
+            if day < 1 || day > max {
+                lints.push(Lint::DayOutOfRange { month, day, max });
+            }
+        }
+
+        if GREGORIAN_YEARS.contains(&year) && !self.is_near(year) {
+            let suggestion = NaiveDate::from_ymd_opt(year, month, day).map(PersianDate::from);
+            lints.push(Lint::LooksGregorian { year, suggestion });
+        } else if !self.is_near(year) {
+            lints.push(Lint::FarFromPresent {
+                year,
+                reference: self.reference_year,
+            });
+        }
+        lints
+    }
+
+    /// Like [`Plausibility::check`] but for a `year/month/day` string,
+    /// fails only if the input doesn't have that shape
+    pub fn check_str(&self, s: &str) -> Result<Vec<Lint>, PersianError> {
+        let (year, month, day) = split_ymd(s)?;
+        Ok(self.check(year, month, day))
+    }
+
+    fn is_near(&self, year: i32) -> bool {
+        year.abs_diff(self.reference_year) <= self.max_years
+    }
+}
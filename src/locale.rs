@@ -0,0 +1,67 @@
+//! Formatting preferences from the operating system's locale (requires the `locale` feature)
+//!
+//! on unix the locale is read from `LC_ALL`, `LC_TIME` and `LANG`, on windows it's the user's
+//! default locale, a persian locale (`fa`, `fa-IR`, `fa_IR.UTF-8`, ..) picks persian digits
+//! ```rust
+//!use chrono_persian::locale::digits_for;
+//!use chrono_persian::Digits;
+//!
+//!assert_eq!(digits_for("fa_IR.UTF-8"), Digits::Persian);
+//!assert_eq!(digits_for("fa-AF"), Digits::Persian);
+//!assert_eq!(digits_for("en_US.UTF-8"), Digits::Latin);
+//!assert_eq!(digits_for("C"), Digits::Latin);
+//! ```
+
+use crate::{CompiledFormat, Digits};
+
+/// Get the locale of the user, such as `fa_IR.UTF-8` or `en-US`, `None` if it isn't set
+pub fn os_locale() -> Option<String> {
+    os_locale_impl().filter(|l| !l.is_empty())
+}
+
+#[cfg(not(windows))]
+fn os_locale_impl() -> Option<String> {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+}
+
+#[cfg(windows)]
+fn os_locale_impl() -> Option<String> {
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetUserDefaultLocaleName(name: *mut u16, len: i32) -> i32;
+    }
+    // LOCALE_NAME_MAX_LENGTH
+    let mut buf = [0u16; 85];
+    // SAFETY: the buffer is as long as the length passed
+    let len = unsafe { GetUserDefaultLocaleName(buf.as_mut_ptr(), buf.len() as i32) };
+    // the length includes the trailing nul, 0 is a failure
+    let len = usize::try_from(len).ok()?.checked_sub(1)?;
+    String::from_utf16(&buf[..len]).ok()
+}
+
+/// the digits a locale writes numbers with, persian for the persian language
+pub fn digits_for(locale: &str) -> Digits {
+    let language = locale
+        .split(['_', '-', '.', '@'])
+        .next()
+        .unwrap_or_default();
+    match language.eq_ignore_ascii_case("fa") || language.eq_ignore_ascii_case("prs") {
+        true => Digits::Persian,
+        false => Digits::Latin,
+    }
+}
+
+/// the digits of the operating system's locale, latin when it can't be read
+pub fn os_digits() -> Digits {
+    os_locale().map_or(Digits::Latin, |l| digits_for(&l))
+}
+
+impl CompiledFormat {
+    /// the same format writing the numbers with the [`os_digits`]
+    pub fn with_os_digits(self) -> Self {
+        self.with_digits(os_digits())
+    }
+}
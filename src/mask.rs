@@ -0,0 +1,150 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::format::digit_value;
+use crate::{is_valid_jalali, PersianDate};
+
+/// Keystroke by keystroke validation of a `year/month/day` input field
+///
+/// the year takes 4 digits, the month and day 1 or 2, digits can be latin, persian or arabic
+/// ```rust
+///use chrono_persian::{InputMask, MaskState};
+///
+///let mask = InputMask::default();
+///assert!(matches!(mask.check("14"), MaskState::Partial { .. }));
+///assert_eq!(mask.check("1403/13"), MaskState::Invalid);
+///
+///let MaskState::Partial { date, suggestions } = mask.check("1403/12/3") else { panic!() };
+///assert_eq!(date.unwrap().to_string(), "1403-12-03");
+///assert_eq!(suggestions, ["1403/12/30"]);
+///
+/// // esfand 1404 has 29 days, the day can't become 30
+///let MaskState::Complete(date) = mask.check("۱۴۰۴/۱۲/۳") else { panic!() };
+///assert_eq!(date.to_string(), "1404-12-03");
+///assert_eq!(mask.check("1404/12/30"), MaskState::Invalid);
+///
+///assert!(matches!(mask.check("1403/08/20"), MaskState::Complete(_)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputMask {
+    pub separator: char,
+}
+
+/// What [`InputMask::check`] thinks of an input
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MaskState {
+    /// no date starts like this
+    Invalid,
+    /// more can be typed
+    Partial {
+        /// the date the input already is, if it's one
+        date: Option<PersianDate>,
+        /// the input with each character that can come next, with latin digits
+        suggestions: Vec<String>,
+    },
+    /// a date nothing more can be typed after
+    Complete(PersianDate),
+}
+
+impl InputMask {
+    pub fn new(separator: char) -> Self {
+        Self { separator }
+    }
+
+    /// Check what's typed so far
+    pub fn check(&self, input: &str) -> MaskState {
+        let Some(input) = self.normalize(input) else {
+            return MaskState::Invalid;
+        };
+        if !self.is_prefix(&input) {
+            return MaskState::Invalid;
+        }
+        let date = self.date(&input);
+        let suggestions: Vec<String> = ('0'..='9')
+            .chain([self.separator])
+            .map(|c| format!("{input}{c}"))
+            .filter(|s| self.is_prefix(s))
+            .collect();
+        match (date, suggestions.is_empty()) {
+            (Some(date), true) => MaskState::Complete(date),
+            (date, _) => MaskState::Partial { date, suggestions },
+        }
+    }
+
+    /// the input with latin digits, `None` if it has other characters
+    fn normalize(&self, input: &str) -> Option<String> {
+        input
+            .chars()
+            .map(|c| match c {
+                c if c == self.separator => Some(c),
+                c => char::from_digit(digit_value(c)?, 10),
+            })
+            .collect()
+    }
+
+    fn date(&self, input: &str) -> Option<PersianDate> {
+        let mut parts = input.split(self.separator);
+        let (Some(y), Some(m), Some(d)) = (parts.next(), parts.next(), parts.next()) else {
+            return None;
+        };
+        PersianDate::from_ymd_opt(y.parse().ok()?, m.parse().ok()?, d.parse().ok()?)
+    }
+
+    /// whether some date starts with `input`, which only has latin digits and separators
+    fn is_prefix(&self, input: &str) -> bool {
+        let parts: Vec<&str> = input.split(self.separator).collect();
+        if parts.len() > 3 {
+            return false;
+        }
+        let last = parts.len() - 1;
+        let year = parts[0];
+        if year.len() > 4 || (last > 0 && year.len() != 4) {
+            return false;
+        }
+        let Some(&month) = parts.get(1) else {
+            return true;
+        };
+        if !fits(month, 12, last > 1) {
+            return false;
+        }
+        let Some(&day) = parts.get(2) else {
+            return true;
+        };
+        let max = (29..=31)
+            .rev()
+            .find(|&d| {
+                let (Ok(y), Ok(m)) = (year.parse(), month.parse()) else {
+                    return false;
+                };
+                is_valid_jalali(y, m, d)
+            })
+            .unwrap_or(0);
+        fits(day, max, false)
+    }
+}
+
+impl Default for InputMask {
+    fn default() -> Self {
+        Self::new('/')
+    }
+}
+
+/// whether a number from 1 to `max` is written (padded or not) as `typed`, or starts with it
+/// when it's not `finished`
+fn fits(typed: &str, max: u32, finished: bool) -> bool {
+    if typed.len() > 2 || (finished && typed.is_empty()) {
+        return false;
+    }
+    (1..=max).any(|n| {
+        [format!("{n:02}"), n.to_string()].iter().any(|s| {
+            if finished {
+                s == typed
+            } else {
+                s.starts_with(typed)
+            }
+        })
+    })
+}
@@ -0,0 +1,112 @@
+use alloc::{vec, vec::Vec};
+use chrono::{DateTime, Datelike, Days, Duration, NaiveTime, TimeZone, Utc};
+use core::ops::Range;
+
+use crate::{BusinessCalendar, PersianDateRange, PersianDateTime, Resolve, Weekend};
+
+/// The working day of a participant outside iran, in their own time zone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingHours<Tz: TimeZone> {
+    pub zone: Tz,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub weekend: Weekend,
+}
+
+impl<Tz: TimeZone> WorkingHours<Tz> {
+    /// the working hours on the days the participant works, between the instants `from` and
+    /// `to`
+    fn windows(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<Range<DateTime<Utc>>> {
+        let mut windows = Vec::new();
+        let mut day = from.with_timezone(&self.zone).date_naive();
+        let last = to.with_timezone(&self.zone).date_naive();
+        while day <= last {
+            let start = self.zone.from_local_datetime(&day.and_time(self.start));
+            let end = self.zone.from_local_datetime(&day.and_time(self.end));
+            if let (false, Some(start), Some(end)) = (
+                self.weekend.contains(day.weekday()),
+                start.earliest(),
+                end.latest(),
+            ) {
+                windows.push(start.to_utc()..end.to_utc());
+            }
+            let Some(next) = day.checked_add_days(Days::new(1)) else {
+                break;
+            };
+            day = next;
+        }
+        windows
+    }
+}
+
+/// the parts of `a` also in one of `b`
+fn intersect(a: &[Range<DateTime<Utc>>], b: &[Range<DateTime<Utc>>]) -> Vec<Range<DateTime<Utc>>> {
+    let mut out = Vec::new();
+    for x in a {
+        for y in b {
+            let range = x.start.max(y.start)..x.end.min(y.end);
+            if range.start < range.end {
+                out.push(range);
+            }
+        }
+    }
+    out
+}
+
+/// Propose meeting times of at least `length` on the business days of `calendar` in `days`,
+/// while iran's office is open (`office` in iran's time) and every participant is working
+///
+/// the slots are in iran's time, for the iranian side, use [`PersianDateTime::in_zone`] to show
+/// them to the others
+/// ```rust
+///use chrono::{Duration, FixedOffset, NaiveTime};
+///use chrono_persian::holidays::official_calendar;
+///use chrono_persian::{meeting_slots, PersianDate, PersianDateRange, Weekend, WorkingHours};
+///
+///let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+///let berlin = WorkingHours {
+///    zone: FixedOffset::east_opt(3600).unwrap(),
+///    start: time(9),
+///    end: time(17),
+///    weekend: Weekend::SATURDAY_SUNDAY,
+///};
+///let week = PersianDateRange::new(
+///    PersianDate::from_ymd_opt(1403, 8, 19).unwrap(),
+///    PersianDate::from_ymd_opt(1403, 8, 25).unwrap(),
+///)
+///.unwrap();
+///let slots = meeting_slots(&official_calendar(), time(8)..time(16), week, &[berlin], Duration::hours(1));
+///
+/// // monday to wednesday are working days on both sides
+///assert_eq!(slots.len(), 3);
+///assert_eq!(slots[0].start.to_string(), "1403-08-21 11:30:00 +03:30");
+///assert_eq!(slots[0].end.to_string(), "1403-08-21 16:00:00 +03:30");
+/// ```
+pub fn meeting_slots<Tz: TimeZone>(
+    calendar: &BusinessCalendar,
+    office: Range<NaiveTime>,
+    days: PersianDateRange,
+    participants: &[WorkingHours<Tz>],
+    length: Duration,
+) -> Vec<Range<PersianDateTime>> {
+    let mut slots = Vec::new();
+    for day in days.days().filter(|d| calendar.is_business_day(*d)) {
+        let start = PersianDateTime::from_local(day, office.start, Resolve::Earliest);
+        let end = PersianDateTime::from_local(day, office.end, Resolve::Latest);
+        let (Some(start), Some(end)) = (start, end) else {
+            continue;
+        };
+        let (start, end) = (start.to_utc(), end.to_utc());
+        let mut windows = vec![start..end];
+        for participant in participants {
+            windows = intersect(&windows, &participant.windows(start, end));
+        }
+        slots.extend(
+            windows
+                .into_iter()
+                .filter(|w| w.end - w.start >= length)
+                .map(|w| PersianDateTime::from(w.start)..PersianDateTime::from(w.end)),
+        );
+    }
+    slots
+}
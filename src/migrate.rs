@@ -0,0 +1,125 @@
+//! Detect and repair jalali dates that were stored in gregorian `DATE` columns
+//!
+//! many legacy databases stored the jalali components as is (`1403-08-20`), the year alone
+//! usually tells them apart from real gregorian dates. note that jalali dates the database
+//! considered invalid (such as `1403-02-31`) were rejected when inserted and can't be recovered
+//! ```rust
+//!use chrono::NaiveDate;
+//!use chrono_persian::migrate::{StoredDateKind, JalaliAsGregorian};
+//!
+//!let column = [
+//!    NaiveDate::from_ymd_opt(1403, 8, 20).unwrap(),
+//!    NaiveDate::from_ymd_opt(2024, 11, 10).unwrap(),
+//!    NaiveDate::from_ymd_opt(1403, 7, 31).unwrap(),
+//!];
+//!let detector = JalaliAsGregorian::default();
+//!assert_eq!(detector.classify(column[0]), StoredDateKind::Jalali);
+//!
+//!let report = detector.scan(column);
+//!assert_eq!((report.jalali, report.gregorian, report.unknown), (1, 1, 1));
+//!
+//!let fixed = detector.fix_all(column);
+//!assert_eq!(fixed[0], NaiveDate::from_ymd_opt(2024, 11, 10));
+//!assert_eq!(fixed[1], NaiveDate::from_ymd_opt(2024, 11, 10));
+//!assert_eq!(fixed[2], None);
+//! ```
+
+use alloc::vec::Vec;
+use chrono::{Datelike, NaiveDate};
+use core::ops::RangeInclusive;
+
+use crate::PersianDate;
+
+/// What a stored value most likely is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StoredDateKind {
+    /// a real gregorian date
+    Gregorian,
+    /// jalali components stored as a gregorian date
+    Jalali,
+    /// neither, e.g. the year is out of both ranges or the components aren't a jalali date
+    Unknown,
+}
+
+/// The result of [`JalaliAsGregorian::scan`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ScanReport {
+    pub gregorian: usize,
+    pub jalali: usize,
+    pub unknown: usize,
+}
+
+impl ScanReport {
+    /// the share of values that look like jalali, from 0 to 1
+    pub fn jalali_ratio(&self) -> f64 {
+        let total = self.gregorian + self.jalali + self.unknown;
+        if total == 0 {
+            return 0.0;
+        }
+        self.jalali as f64 / total as f64
+    }
+}
+
+/// Heuristics telling jalali values apart from gregorian ones by their year
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JalaliAsGregorian {
+    /// years taken as jalali, `1300..=1499` by default
+    pub jalali_years: RangeInclusive<i32>,
+    /// years taken as gregorian, `1900..=2199` by default
+    pub gregorian_years: RangeInclusive<i32>,
+}
+
+impl Default for JalaliAsGregorian {
+    fn default() -> Self {
+        Self {
+            jalali_years: 1300..=1499,
+            gregorian_years: 1900..=2199,
+        }
+    }
+}
+
+impl JalaliAsGregorian {
+    /// Guess what a stored value is
+    pub fn classify(&self, date: NaiveDate) -> StoredDateKind {
+        if self.gregorian_years.contains(&date.year()) {
+            StoredDateKind::Gregorian
+        } else if self.jalali_years.contains(&date.year()) && reinterpret(date).is_some() {
+            StoredDateKind::Jalali
+        } else {
+            StoredDateKind::Unknown
+        }
+    }
+
+    /// Get the real gregorian date of a stored value, `None` if it's [`StoredDateKind::Unknown`]
+    pub fn fix(&self, date: NaiveDate) -> Option<NaiveDate> {
+        match self.classify(date) {
+            StoredDateKind::Gregorian => Some(date),
+            StoredDateKind::Jalali => reinterpret(date)?.to_gregorian(),
+            StoredDateKind::Unknown => None,
+        }
+    }
+
+    /// Fix every value of a column, see [`JalaliAsGregorian::fix`]
+    pub fn fix_all<I: IntoIterator<Item = NaiveDate>>(&self, dates: I) -> Vec<Option<NaiveDate>> {
+        dates.into_iter().map(|d| self.fix(d)).collect()
+    }
+
+    /// Count the kinds of values in a column, to decide whether it needs a migration at all
+    pub fn scan<I: IntoIterator<Item = NaiveDate>>(&self, dates: I) -> ScanReport {
+        dates
+            .into_iter()
+            .fold(ScanReport::default(), |mut report, d| {
+                match self.classify(d) {
+                    StoredDateKind::Gregorian => report.gregorian += 1,
+                    StoredDateKind::Jalali => report.jalali += 1,
+                    StoredDateKind::Unknown => report.unknown += 1,
+                }
+                report
+            })
+    }
+}
+
+/// Read the components of a stored value as a jalali date, `None` if they aren't one
+pub fn reinterpret(date: NaiveDate) -> Option<PersianDate> {
+    PersianDate::from_ymd_opt(date.year(), date.month(), date.day())
+}
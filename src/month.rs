@@ -0,0 +1,129 @@
+use core::iter::FusedIterator;
+
+use crate::{calendar, PersianDate};
+
+/// A month of a persian year, such as aban 1403
+/// ```rust
+///use chrono_persian::PersianMonthOfYear;
+///
+///let esfand = PersianMonthOfYear::new(1403, 12).unwrap();
+///assert_eq!(esfand.num_days(), 30);
+///
+///let days: Vec<_> = esfand.days().map(|d| d.day()).collect();
+///assert_eq!(days, (1..=30).collect::<Vec<_>>());
+///
+///for day in PersianMonthOfYear::new(1404, 12).unwrap() {
+///    assert_eq!(day.month(), 12);
+///}
+///assert!(PersianMonthOfYear::new(1403, 13).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersianMonthOfYear {
+    year: i32,
+    month: u32,
+}
+
+impl PersianMonthOfYear {
+    /// `None` if the month isn't from 1 to 12 or the year is out of range
+    pub fn new(year: i32, month: u32) -> Option<Self> {
+        PersianDate::from_ymd_opt(year, month, 1).map(|_| Self { year, month })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// the month, starting from 1 (farvardin)
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// the number of days, from 29 to 31
+    pub fn num_days(&self) -> u32 {
+        calendar::days_in_month(self.year, self.month)
+    }
+
+    pub fn first_day(&self) -> PersianDate {
+        PersianDate::from_ymd_unchecked(self.year, self.month, 1)
+    }
+
+    pub fn last_day(&self) -> PersianDate {
+        PersianDate::from_ymd_unchecked(self.year, self.month, self.num_days())
+    }
+
+    /// the days of the month, in order
+    pub fn days(&self) -> MonthDays {
+        MonthDays {
+            month: *self,
+            front: 1,
+            back: self.num_days(),
+        }
+    }
+}
+
+impl PersianDate {
+    /// the month the date is in
+    pub fn month_of_year(&self) -> PersianMonthOfYear {
+        PersianMonthOfYear {
+            year: self.year(),
+            month: self.month(),
+        }
+    }
+}
+
+impl IntoIterator for PersianMonthOfYear {
+    type Item = PersianDate;
+    type IntoIter = MonthDays;
+
+    fn into_iter(self) -> MonthDays {
+        self.days()
+    }
+}
+
+/// Iterator over the days of a [`PersianMonthOfYear`]
+#[derive(Debug, Clone)]
+pub struct MonthDays {
+    month: PersianMonthOfYear,
+    /// the next day from the front and from the back, empty once `front > back`
+    front: u32,
+    back: u32,
+}
+
+impl Iterator for MonthDays {
+    type Item = PersianDate;
+
+    fn next(&mut self) -> Option<PersianDate> {
+        if self.front > self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(PersianDate::from_ymd_unchecked(
+            self.month.year,
+            self.month.month,
+            self.front - 1,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back + 1).saturating_sub(self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for MonthDays {
+    fn next_back(&mut self) -> Option<PersianDate> {
+        if self.front > self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(PersianDate::from_ymd_unchecked(
+            self.month.year,
+            self.month.month,
+            self.back + 1,
+        ))
+    }
+}
+
+impl ExactSizeIterator for MonthDays {}
+
+impl FusedIterator for MonthDays {}
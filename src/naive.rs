@@ -0,0 +1,35 @@
+use chrono::NaiveDateTime;
+
+use crate::{resolve, PersianDateTime, Resolve};
+
+/// Convert a `NaiveDateTime` saying which time zone its wall clock is in
+///
+/// [`ToPersian`](crate::ToPersian) for `NaiveDateTime` reads the value as utc and returns
+/// iran's wall clock in a chrono type, these methods make the assumption explicit and
+/// return a [`PersianDateTime`]
+/// ```rust
+///use chrono::NaiveDateTime;
+///use chrono_persian::NaiveDateTimeExt;
+///
+///let naive = NaiveDateTime::parse_from_str("2024-11-26 08:55:11", "%Y-%m-%d %H:%M:%S").unwrap();
+///assert_eq!(naive.to_persian_assuming_utc().unwrap().to_string(), "1403-09-06 12:25:11 +03:30");
+///assert_eq!(naive.to_persian_assuming_tehran().unwrap().to_string(), "1403-09-06 08:55:11 +03:30");
+/// ```
+pub trait NaiveDateTimeExt {
+    /// Read the value as a utc wall clock, like the `ToPersian` impl does
+    fn to_persian_assuming_utc(&self) -> Option<PersianDateTime>;
+
+    /// Read the value as a wall clock in iran, a time skipped by daylight saving time is moved
+    /// forward by the length of the gap and a repeated one is the earliest, see [`Resolve`]
+    fn to_persian_assuming_tehran(&self) -> Option<PersianDateTime>;
+}
+
+impl NaiveDateTimeExt for NaiveDateTime {
+    fn to_persian_assuming_utc(&self) -> Option<PersianDateTime> {
+        Some(PersianDateTime::from(self.and_utc()))
+    }
+
+    fn to_persian_assuming_tehran(&self) -> Option<PersianDateTime> {
+        resolve::tehran_local(*self, Resolve::Earliest).map(PersianDateTime::from)
+    }
+}
@@ -0,0 +1,64 @@
+/// the persian names of the months, from farvardin
+/// ```rust
+///use chrono_persian::{MONTH_NAMES_EN, MONTH_NAMES_FA, WEEKDAY_NAMES_FA};
+///
+///let options: Vec<_> = (1..).zip(MONTH_NAMES_FA).collect();
+///assert_eq!(options[7], (8, "آبان"));
+///assert_eq!(MONTH_NAMES_EN[7], "Aban");
+///assert_eq!(WEEKDAY_NAMES_FA[6], "جمعه");
+/// ```
+pub const MONTH_NAMES_FA: [&str; 12] = [
+    "فروردین",
+    "اردیبهشت",
+    "خرداد",
+    "تیر",
+    "مرداد",
+    "شهریور",
+    "مهر",
+    "آبان",
+    "آذر",
+    "دی",
+    "بهمن",
+    "اسفند",
+];
+
+/// the latin spelling of the persian names of the months, from farvardin
+pub const MONTH_NAMES_EN: [&str; 12] = [
+    "Farvardin",
+    "Ordibehesht",
+    "Khordad",
+    "Tir",
+    "Mordad",
+    "Shahrivar",
+    "Mehr",
+    "Aban",
+    "Azar",
+    "Dey",
+    "Bahman",
+    "Esfand",
+];
+
+/// the persian names of the days of the week, from saturday
+pub const WEEKDAY_NAMES_FA: [&str; 7] = [
+    "شنبه",
+    "یکشنبه",
+    "دوشنبه",
+    "سه‌شنبه",
+    "چهارشنبه",
+    "پنج‌شنبه",
+    "جمعه",
+];
+
+/// the english names of the days of the week, from saturday
+pub const WEEKDAY_NAMES_EN: [&str; 7] = [
+    "Saturday",
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+];
+
+/// the persian names of the seasons, from bahar
+pub(crate) const SEASON_NAMES_FA: [&str; 4] = ["بهار", "تابستان", "پاییز", "زمستان"];
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+
+use crate::{tehran_midnight, to_tehran, PersianDate};
+
+/// The jalali days a utc calendar day overlaps with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JalaliDaySpan {
+    /// the jalali day (in iran) the utc day starts in
+    pub start: PersianDate,
+    /// the instant the next jalali day starts and that day, `None` if the whole utc day is in
+    /// `start`
+    pub next: Option<(DateTime<Utc>, PersianDate)>,
+}
+
+/// Get the one or two jalali days a utc calendar day overlaps with, and where it's cut
+///
+/// iran is ahead of utc, so the evening of a utc day is already the next jalali day
+/// ```rust
+///use chrono::NaiveDate;
+///use chrono_persian::gregorian_day_to_jalali_span;
+///
+///let span = gregorian_day_to_jalali_span(NaiveDate::from_ymd_opt(2024, 11, 9).unwrap()).unwrap();
+///assert_eq!(span.start.to_string(), "1403-08-19");
+///
+///let (cut, next) = span.next.unwrap();
+///assert_eq!(cut.to_string(), "2024-11-09 20:30:00 UTC");
+///assert_eq!(next.to_string(), "1403-08-20");
+/// ```
+pub fn gregorian_day_to_jalali_span(date: NaiveDate) -> Option<JalaliDaySpan> {
+    let start = date.and_hms_opt(0, 0, 0)?.and_utc();
+    let end = start.checked_add_days(Days::new(1))?;
+    let local = to_tehran(&start).date_naive();
+    let next_day = local.succ_opt()?;
+    let cut = tehran_midnight(next_day)?.to_utc();
+    Some(JalaliDaySpan {
+        start: PersianDate::from(local),
+        next: (cut < end).then(|| (cut, PersianDate::from(next_day))),
+    })
+}
@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+
+use crate::{calendar, MonthSegment, PersianDate, PersianDateRange, Weekend};
+
+/// How the days of a payroll month are counted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PayrollDays {
+    /// the days of the month, 31 in the first half of the year and 29 or 30 after
+    #[default]
+    Actual,
+    /// every full month is 30 days, like the monthly wage of the labor law (30 daily wages)
+    Thirty,
+}
+
+/// An employee's part of one payroll month
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PayrollMonth {
+    /// the days of the month the employment covers
+    pub segment: MonthSegment,
+    /// the days to pay for under the chosen [`PayrollDays`]
+    pub paid_days: u32,
+    /// the days a full month is paid for
+    pub full_days: u32,
+}
+
+impl PayrollMonth {
+    /// the covered part of the month's pay, from 0 to 1
+    pub fn fraction(&self) -> f64 {
+        self.paid_days as f64 / self.full_days as f64
+    }
+
+    /// the working days of the covered part, skipping the `weekend`
+    pub fn working_days(&self, weekend: Weekend) -> u32 {
+        self.segment.range.business_days(weekend)
+    }
+}
+
+/// Get the payroll period of a persian month, from its first to its last day
+/// ```rust
+///use chrono_persian::payroll_period;
+///
+///let esfand = payroll_period(1403, 12).unwrap();
+///assert_eq!(esfand.end().to_string(), "1403-12-30");
+///assert!(payroll_period(1403, 13).is_none());
+/// ```
+pub fn payroll_period(year: i32, month: u32) -> Option<PersianDateRange> {
+    let start = PersianDate::from_ymd_opt(year, month, 1)?;
+    let end = PersianDate::from_ymd_opt(year, month, calendar::days_in_month(year, month))?;
+    PersianDateRange::new(start, end)
+}
+
+/// Split an employment interval into the payroll months it overlaps, with the worked days of
+/// each
+///
+/// with [`PayrollDays::Thirty`] a full month is paid as 30 days and a partial one as its
+/// worked days, at most 30
+/// ```rust
+///use chrono_persian::{payroll_months, PayrollDays, PersianDate, PersianDateRange, Weekend};
+///
+///let employment = PersianDateRange::new(
+///    PersianDate::from_ymd_opt(1403, 6, 10).unwrap(),
+///    PersianDate::from_ymd_opt(1403, 8, 15).unwrap(),
+///)
+///.unwrap();
+///
+///let actual = payroll_months(&employment, PayrollDays::Actual);
+///let days: Vec<_> = actual.iter().map(|m| (m.segment.month(), m.paid_days, m.full_days)).collect();
+///assert_eq!(days, [(6, 22, 31), (7, 30, 30), (8, 15, 30)]);
+///
+///let thirty = payroll_months(&employment, PayrollDays::Thirty);
+///let days: Vec<_> = thirty.iter().map(|m| (m.segment.month(), m.paid_days)).collect();
+///assert_eq!(days, [(6, 22), (7, 30), (8, 15)]);
+///assert_eq!(thirty[1].fraction(), 1.0);
+///assert_eq!(thirty[2].working_days(Weekend::default()), 11);
+/// ```
+pub fn payroll_months(employment: &PersianDateRange, days: PayrollDays) -> Vec<PayrollMonth> {
+    employment
+        .split_by_month()
+        .into_iter()
+        .map(|segment| {
+            let (paid_days, full_days) = match days {
+                PayrollDays::Actual => (segment.days, segment.month_days),
+                PayrollDays::Thirty if segment.days == segment.month_days => (30, 30),
+                PayrollDays::Thirty => (segment.days.min(30), 30),
+            };
+            PayrollMonth {
+                segment,
+                paid_days,
+                full_days,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use core::ops::Range;
+
+use crate::{tehran_midnight, PersianDate, PersianUnit, SchemePeriod};
+
+/// A specific day, month, quarter or year of the persian calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PersianPeriod {
+    Day(PersianDate),
+    /// `month` is 1 to 12
+    Month {
+        year: i32,
+        month: u32,
+    },
+    /// `quarter` is 1 to 4, starting at farvardin, tir, mehr and dey
+    Quarter {
+        year: i32,
+        quarter: u32,
+    },
+    Year(i32),
+    /// a period of a [`crate::PeriodScheme`], such as a fiscal year
+    Scheme(SchemePeriod),
+}
+
+impl PersianPeriod {
+    /// the first day of the period, `None` if the period is invalid or out of range
+    pub fn first_day(&self) -> Option<PersianDate> {
+        match *self {
+            PersianPeriod::Day(date) => Some(date),
+            PersianPeriod::Month { year, month } => PersianDate::from_ymd_opt(year, month, 1),
+            PersianPeriod::Quarter { year, quarter } if (1..=4).contains(&quarter) => {
+                PersianDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1)
+            }
+            PersianPeriod::Quarter { .. } => None,
+            PersianPeriod::Year(year) => PersianDate::from_ymd_opt(year, 1, 1),
+            PersianPeriod::Scheme(period) => period.first_day(),
+        }
+    }
+
+    /// the calendar unit of the period
+    pub fn unit(&self) -> PersianUnit {
+        match self {
+            PersianPeriod::Day(_) => PersianUnit::Day,
+            PersianPeriod::Month { .. } => PersianUnit::Month,
+            PersianPeriod::Quarter { .. } => PersianUnit::Quarter,
+            PersianPeriod::Year(_) => PersianUnit::Year,
+            PersianPeriod::Scheme(period) => PersianUnit::Scheme(period.scheme()),
+        }
+    }
+
+    /// Get the `[start, end)` utc instants of the period in iran's time, such as for a database
+    /// query, `None` if the period is invalid or out of range
+    /// ```rust
+    ///use chrono_persian::{PeriodScheme, PersianDate, PersianPeriod};
+    ///
+    ///let aban = PersianPeriod::Month { year: 1403, month: 8 }.utc_range().unwrap();
+    ///assert_eq!(aban.start.to_string(), "2024-10-21 20:30:00 UTC");
+    ///assert_eq!(aban.end.to_string(), "2024-11-20 20:30:00 UTC");
+    ///
+    ///let day = PersianPeriod::Day(PersianDate::from_ymd_opt(1403, 8, 20).unwrap());
+    ///assert_eq!(day.utc_range().unwrap().start.to_string(), "2024-11-09 20:30:00 UTC");
+    ///
+    ///assert!(PersianPeriod::Quarter { year: 1403, quarter: 5 }.utc_range().is_none());
+    ///
+    ///let fiscal = PeriodScheme::fiscal_year(7).period(1403, 1).unwrap();
+    ///let range = PersianPeriod::from(fiscal).utc_range().unwrap();
+    ///assert_eq!(range.end.to_string(), "2025-09-22 20:30:00 UTC");
+    /// ```
+    pub fn utc_range(&self) -> Option<Range<DateTime<Utc>>> {
+        let start = self.first_day()?;
+        let end = self.unit().next(start)?;
+        Some(
+            tehran_midnight(start.to_gregorian()?)?.to_utc()
+                ..tehran_midnight(end.to_gregorian()?)?.to_utc(),
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{IntoInstant, PersianDateTime};
+
+/// The fields of a persian date and time as plain numbers, such as for a template engine
+///
+/// with the `serde` feature it serializes as a map of the fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct JalaliFields {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    /// the day of the persian week, 1 for saturday to 7 for friday
+    pub weekday: u32,
+}
+
+impl PersianDateTime {
+    /// the fields of the wall clock as plain numbers
+    ///
+    /// called `jalali_fields` to stay apart from `PersianField::to_persian_fields` of the
+    /// `derive` feature
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::{JalaliFields, PersianDateTime};
+    ///
+    ///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let fields = PersianDateTime::from(utc).jalali_fields();
+    ///assert_eq!(
+    ///    fields,
+    ///    JalaliFields { year: 1403, month: 8, day: 20, hour: 2, minute: 8, second: 28, weekday: 2 }
+    ///);
+    /// ```
+    pub fn jalali_fields(&self) -> JalaliFields {
+        JalaliFields {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.second(),
+            weekday: (self.date().weekday().num_days_from_monday() + 2) % 7 + 1,
+        }
+    }
+}
+
+/// the fields in iran's time of any kind of instant, see [`crate::to_persian_any`]
+/// ```rust
+///use chrono_persian::to_persian_fields;
+///
+///let fields = to_persian_fields(1731191908_i64).unwrap();
+///assert_eq!((fields.year, fields.month, fields.day), (1403, 8, 20));
+/// ```
+pub fn to_persian_fields<T: IntoInstant>(value: T) -> Option<JalaliFields> {
+    value
+        .into_instant()
+        .map(|i| PersianDateTime::from(i).jalali_fields())
+}
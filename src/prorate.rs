@@ -0,0 +1,68 @@
+use alloc::vec::Vec;
+
+use crate::{calendar, PersianDate, PersianDateRange};
+
+/// The part of a [`PersianDateRange`] that falls within one persian month
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MonthSegment {
+    /// the days of the month covered by the range
+    pub range: PersianDateRange,
+    /// number of days covered
+    pub days: u32,
+    /// number of days the whole month has
+    pub month_days: u32,
+}
+
+impl MonthSegment {
+    /// the persian year of the month
+    pub fn year(&self) -> i32 {
+        self.range.start().year()
+    }
+
+    /// the persian month
+    pub fn month(&self) -> u32 {
+        self.range.start().month()
+    }
+
+    /// the covered part of the month, from 0 to 1
+    pub fn fraction(&self) -> f64 {
+        self.days as f64 / self.month_days as f64
+    }
+}
+
+impl PersianDateRange {
+    /// Split the range at persian month boundaries, for prorated billing and payroll
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianDateRange};
+    ///
+    ///let start = PersianDate::from_ymd_opt(1403, 6, 16).unwrap();
+    ///let end = PersianDate::from_ymd_opt(1403, 8, 10).unwrap();
+    ///let segments = PersianDateRange::new(start, end).unwrap().split_by_month();
+    ///
+    ///let days: Vec<_> = segments.iter().map(|s| (s.month(), s.days, s.month_days)).collect();
+    ///assert_eq!(days, [(6, 16, 31), (7, 30, 30), (8, 10, 30)]);
+    ///assert_eq!(segments[1].fraction(), 1.0);
+    /// ```
+    pub fn split_by_month(&self) -> Vec<MonthSegment> {
+        let mut segments = Vec::new();
+        let mut start = self.start();
+        loop {
+            let month_days = calendar::days_in_month(start.year(), start.month());
+            let month_end =
+                PersianDate::from_ymd_unchecked(start.year(), start.month(), month_days);
+            let end = month_end.min(self.end());
+            segments.push(MonthSegment {
+                range: PersianDateRange::new(start, end).unwrap_or(*self),
+                days: end.day() - start.day() + 1,
+                month_days,
+            });
+            if end == self.end() {
+                return segments;
+            }
+            start = match start.month() {
+                12 => PersianDate::from_ymd_unchecked(start.year() + 1, 1, 1),
+                m => PersianDate::from_ymd_unchecked(start.year(), m + 1, 1),
+            };
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::format::{digit_value, write_number};
+use crate::names::SEASON_NAMES_FA;
+use crate::{calendar, Digits, PersianDate, PersianDateRange, PersianError, PersianPeriod};
+
+/// A quarter (season) of a persian year, such as tabestan 1403
+///
+/// displays as `Q2 1403`, or as `تابستان ۱۴۰۳` with `{:#}`, and parses from both
+/// ```rust
+///use chrono_persian::PersianQuarter;
+///
+///let q: PersianQuarter = "Q2 1403".parse().unwrap();
+///assert_eq!(q, "تابستان ۱۴۰۳".parse().unwrap());
+///assert_eq!(q.to_string(), "Q2 1403");
+///assert_eq!(format!("{q:#}"), "تابستان ۱۴۰۳");
+///
+///let range = q.to_range();
+///assert_eq!(range.start().to_string(), "1403-04-01");
+///assert_eq!(range.end().to_string(), "1403-06-31");
+///
+///assert_eq!(q.succ().unwrap().to_string(), "Q3 1403");
+///assert!("Q5 1403".parse::<PersianQuarter>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersianQuarter {
+    year: i32,
+    quarter: u32,
+}
+
+impl PersianQuarter {
+    /// `None` if the quarter isn't from 1 to 4 or the year is out of range
+    pub fn new(year: i32, quarter: u32) -> Option<Self> {
+        if !(1..=4).contains(&quarter) {
+            return None;
+        }
+        PersianDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1).map(|_| Self { year, quarter })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// the quarter, from 1 (bahar) to 4 (zemestan)
+    pub fn quarter(&self) -> u32 {
+        self.quarter
+    }
+
+    /// the farsi name of the season
+    pub fn season_name(&self) -> &'static str {
+        SEASON_NAMES_FA[self.quarter as usize - 1]
+    }
+
+    pub fn first_day(&self) -> PersianDate {
+        PersianDate::from_ymd_unchecked(self.year, (self.quarter - 1) * 3 + 1, 1)
+    }
+
+    pub fn last_day(&self) -> PersianDate {
+        let month = self.quarter * 3;
+        PersianDate::from_ymd_unchecked(self.year, month, calendar::days_in_month(self.year, month))
+    }
+
+    /// the days of the quarter, from its first to its last day
+    pub fn to_range(&self) -> PersianDateRange {
+        PersianDateRange::new(self.first_day(), self.last_day()).expect("first day before last")
+    }
+
+    /// the next quarter, `None` when out of range
+    pub fn succ(&self) -> Option<Self> {
+        match self.quarter {
+            4 => Self::new(self.year.checked_add(1)?, 1),
+            q => Self::new(self.year, q + 1),
+        }
+    }
+
+    /// the previous quarter, `None` when out of range
+    pub fn pred(&self) -> Option<Self> {
+        match self.quarter {
+            1 => Self::new(self.year.checked_sub(1)?, 4),
+            q => Self::new(self.year, q - 1),
+        }
+    }
+}
+
+impl PersianDate {
+    /// the quarter the date is in
+    pub fn quarter_of_year(&self) -> PersianQuarter {
+        PersianQuarter {
+            year: self.year(),
+            quarter: (self.month() - 1) / 3 + 1,
+        }
+    }
+}
+
+impl From<PersianQuarter> for PersianPeriod {
+    fn from(value: PersianQuarter) -> Self {
+        PersianPeriod::Quarter {
+            year: value.year,
+            quarter: value.quarter,
+        }
+    }
+}
+
+impl fmt::Display for PersianQuarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{} ", self.season_name())?;
+            write_number(f, self.year, 0, Digits::Persian)
+        } else {
+            write!(f, "Q{} {}", self.quarter, self.year)
+        }
+    }
+}
+
+impl FromStr for PersianQuarter {
+    type Err = PersianError;
+
+    /// Parse `Q2 1403` or `تابستان ۱۴۰۳`, the year can be written with any digits
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, year) = s
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or(PersianError::InvalidFormat)?;
+        let quarter = match name.strip_prefix(['Q', 'q']) {
+            Some(n) => parse_number(n).ok_or(PersianError::InvalidFormat)?,
+            // an older spelling of paeez
+            None if name == "پائیز" => 3,
+            None => {
+                SEASON_NAMES_FA
+                    .iter()
+                    .position(|s| *s == name)
+                    .ok_or(PersianError::InvalidFormat)? as u32
+                    + 1
+            }
+        };
+        let year = parse_number(year.trim_start()).ok_or(PersianError::InvalidFormat)?;
+        let year = i32::try_from(year).map_err(|_| PersianError::InvalidFormat)?;
+        Self::new(year, quarter).ok_or(PersianError::InvalidFormat)
+    }
+}
+
+/// a non-negative number in latin, persian or arabic digits
+fn parse_number(s: &str) -> Option<u32> {
+    if s.is_empty() {
+        return None;
+    }
+    s.chars()
+        .try_fold(0u32, |n, c| n.checked_mul(10)?.checked_add(digit_value(c)?))
+}
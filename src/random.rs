@@ -0,0 +1,52 @@
+use chrono::Days;
+use rand::distr::Distribution;
+use rand::{Rng, RngExt};
+use std::ops::RangeInclusive;
+
+use crate::{PersianDate, PersianDateRange, PersianDays};
+
+/// Sample dates uniformly from the range, every day is equally likely (requires the `rand` feature)
+/// ```rust
+///use chrono_persian::{PersianDate, PersianDateRange};
+///use rand::distr::Distribution;
+///
+///let start = PersianDate::from_ymd_opt(1403, 12, 1).unwrap();
+///let end = PersianDate::from_ymd_opt(1403, 12, 30).unwrap();
+///let range = PersianDateRange::new(start, end).unwrap();
+///
+///for date in range.sample_iter(rand::rng()).take(100) {
+///    assert!(range.contains(&date));
+///}
+/// ```
+impl Distribution<PersianDate> for PersianDateRange {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PersianDate {
+        let (Some(start), Some(end)) = (self.start().to_gregorian(), self.end().to_gregorian())
+        else {
+            return self.start();
+        };
+        let days = (end - start).num_days() as u64;
+        PersianDate::from(start + Days::new(rng.random_range(0..=days)))
+    }
+}
+
+/// Get a random valid date within the given persian years (requires the `rand` feature)
+///
+/// returns `None` if the range is empty or out of range
+/// ```rust
+///use chrono_persian::random_date_in;
+///
+///let date = random_date_in(&mut rand::rng(), 1400..=1403).unwrap();
+///assert!((1400..=1403).contains(&date.year()));
+///
+///assert!(random_date_in(&mut rand::rng(), 1403..=1400).is_none());
+///assert!(random_date_in(&mut rand::rng(), 1403..=i32::MAX).is_none());
+/// ```
+pub fn random_date_in<R: Rng + ?Sized>(
+    rng: &mut R,
+    years: RangeInclusive<i32>,
+) -> Option<PersianDate> {
+    let start = PersianDate::from_ymd_opt(*years.start(), 1, 1)?;
+    let end = PersianDate::from_ymd_opt(years.end().checked_add(1)?, 1, 1)?
+        .checked_sub(PersianDays::new(1))?;
+    Some(PersianDateRange::new(start, end)?.sample(rng))
+}
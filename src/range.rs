@@ -0,0 +1,133 @@
+use alloc::string::String;
+
+use crate::format::write_number;
+use crate::names::MONTH_NAMES_FA;
+use crate::{Digits, PersianDate};
+
+/// An inclusive range of persian dates, `start` is never after `end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PersianDateRange {
+    start: PersianDate,
+    end: PersianDate,
+}
+
+impl PersianDateRange {
+    /// Make a range from `start` to `end` (inclusive), `None` if `start` is after `end`
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianDateRange};
+    ///
+    ///let start = PersianDate::from_ymd_opt(1403, 1, 1).unwrap();
+    ///let end = PersianDate::from_ymd_opt(1403, 6, 31).unwrap();
+    ///let range = PersianDateRange::new(start, end).unwrap();
+    ///assert!(range.contains(&PersianDate::from_ymd_opt(1403, 3, 14).unwrap()));
+    ///
+    ///assert!(PersianDateRange::new(end, start).is_none());
+    /// ```
+    pub fn new(start: PersianDate, end: PersianDate) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// the first day of the range
+    pub fn start(&self) -> PersianDate {
+        self.start
+    }
+
+    /// the last day of the range
+    pub fn end(&self) -> PersianDate {
+        self.end
+    }
+
+    pub fn contains(&self, date: &PersianDate) -> bool {
+        self.start <= *date && *date <= self.end
+    }
+
+    /// the smallest range containing all the dates, `None` when there are none
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianDateRange};
+    ///
+    ///let column = ["1403/05/02", "1402/12/29", "1403/01/15"].map(|d| d.parse::<PersianDate>().unwrap());
+    ///let range = PersianDateRange::bounding(column).unwrap();
+    ///assert_eq!(range.start().to_string(), "1402-12-29");
+    ///assert_eq!(range.end().to_string(), "1403-05-02");
+    ///
+    ///assert!(PersianDateRange::bounding([]).is_none());
+    /// ```
+    pub fn bounding<I: IntoIterator<Item = PersianDate>>(dates: I) -> Option<Self> {
+        let mut dates = dates.into_iter();
+        let first = dates.next()?;
+        Some(dates.fold(Self::new(first, first)?, |range, d| range.extend(d)))
+    }
+
+    /// the smallest range containing the range and `date`
+    pub fn extend(&self, date: PersianDate) -> Self {
+        Self {
+            start: self.start.min(date),
+            end: self.end.max(date),
+        }
+    }
+
+    /// the smallest range containing both ranges, the earlier start and the later end
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianDateRange};
+    ///
+    ///let day = |m, d| PersianDate::from_ymd_opt(1403, m, d).unwrap();
+    ///let spring = PersianDateRange::new(day(1, 1), day(3, 31)).unwrap();
+    ///let summer = PersianDateRange::new(day(4, 1), day(6, 31)).unwrap();
+    ///let khordad = PersianDateRange::new(day(3, 1), day(4, 15)).unwrap();
+    ///
+    ///assert_eq!(spring.hull(&summer), PersianDateRange::new(day(1, 1), day(6, 31)).unwrap());
+    ///assert_eq!(spring.intersection(&khordad), PersianDateRange::new(day(3, 1), day(3, 31)));
+    ///assert_eq!(spring.intersection(&summer), None);
+    /// ```
+    pub fn hull(&self, other: &Self) -> Self {
+        Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// the days in both ranges, the later start and the earlier end, `None` if they don't
+    /// overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Write the range in farsi words, leaving out the month and year of the start when they
+    /// are the same as the end's
+    /// ```rust
+    ///use chrono_persian::{Digits, PersianDate, PersianDateRange};
+    ///
+    ///let range = |a: &str, b: &str| PersianDateRange::new(a.parse().unwrap(), b.parse().unwrap()).unwrap();
+    ///assert_eq!(range("1403/08/10", "1403/08/15").format_elided(Digits::Persian), "۱۰ تا ۱۵ آبان ۱۴۰۳");
+    ///assert_eq!(range("1403/08/28", "1403/09/02").format_elided(Digits::Persian), "۲۸ آبان تا ۲ آذر ۱۴۰۳");
+    ///assert_eq!(range("1403/12/25", "1404/01/05").format_elided(Digits::Latin), "25 اسفند 1403 تا 5 فروردین 1404");
+    ///assert_eq!(range("1403/08/10", "1403/08/10").format_elided(Digits::Latin), "10 آبان 1403");
+    /// ```
+    pub fn format_elided(&self, digits: Digits) -> String {
+        let (start, end) = (self.start, self.end);
+        let mut out = String::new();
+        if start != end {
+            write_day(&mut out, start, digits);
+            if (start.year(), start.month()) != (end.year(), end.month()) {
+                out.push(' ');
+                out.push_str(MONTH_NAMES_FA[start.month() as usize - 1]);
+            }
+            if start.year() != end.year() {
+                out.push(' ');
+                write_number(&mut out, start.year(), 0, digits)
+                    .expect("writing to a string can't fail");
+            }
+            out.push_str(" تا ");
+        }
+        write_day(&mut out, end, digits);
+        out.push(' ');
+        out.push_str(MONTH_NAMES_FA[end.month() as usize - 1]);
+        out.push(' ');
+        write_number(&mut out, end.year(), 0, digits).expect("writing to a string can't fail");
+        out
+    }
+}
+
+fn write_day(out: &mut String, date: PersianDate, digits: Digits) {
+    write_number(out, date.day() as i32, 0, digits).expect("writing to a string can't fail");
+}
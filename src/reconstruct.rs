@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+use chrono::NaiveDate;
+
+use crate::format::digit_value;
+use crate::PersianDate;
+
+/// A jalali date read from a scanned document with some parts unreadable, such as `__/08/20`
+///
+/// a year below 100 is only the last two digits of the year, as in `03/08/20`
+/// ```rust
+///use chrono_persian::{PartialDate, PersianDate};
+///
+///let read = PartialDate::parse("__/08/20").unwrap();
+///assert_eq!(read, PartialDate { year: None, month: Some(8), day: Some(20) });
+///
+///let reference = PersianDate::from_ymd_opt(1403, 2, 1).unwrap();
+///let dates: Vec<_> = read.candidates(reference, 2).iter().map(|d| d.to_string()).collect();
+///assert_eq!(dates, ["1402-08-20", "1403-08-20", "1401-08-20", "1404-08-20"]);
+///
+///let two_digits = PartialDate::parse("۰۳/۱۲/۳۰").unwrap();
+///let dates = two_digits.candidates(reference, 50);
+///assert_eq!(dates.len(), 1);
+///assert_eq!(dates[0].to_string(), "1403-12-30");
+///
+///assert!(PartialDate::parse("1403/08").is_none());
+///assert!(PartialDate::parse("3000000000/08/20").is_none());
+///
+///let any_year = PartialDate { year: None, month: Some(8), day: Some(20) };
+///assert_eq!(any_year.candidates(reference, u32::MAX).len(), 524_286);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PartialDate {
+    pub year: Option<i32>,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+}
+
+impl PartialDate {
+    /// Read `year/month/day`, separated by `/`, `-` or `.`, with `_`, `?` or `*` for unreadable
+    /// digits, a part with any of them is unknown. digits of any kind are accepted
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.trim().split(['/', '-', '.']);
+        let year = read_part(parts.next()?)?;
+        let month = read_part(parts.next()?)?;
+        let day = read_part(parts.next()?)?;
+        if parts.next().is_some() {
+            return None;
+        }
+        let year = match year {
+            Some(y) => Some(i32::try_from(y).ok()?),
+            None => None,
+        };
+        Some(Self { year, month, day })
+    }
+
+    /// whether `date` has the known parts
+    pub fn matches(&self, date: PersianDate) -> bool {
+        let year = match self.year {
+            Some(y @ 0..=99) => date.year().rem_euclid(100) == y,
+            Some(y) => date.year() == y,
+            None => true,
+        };
+        year && self.month.is_none_or(|m| m == date.month())
+            && self.day.is_none_or(|d| d == date.day())
+    }
+
+    /// the dates with the known parts no more than `years` years from `reference`, the most
+    /// plausible first: the closest to `reference`, the earlier of two as close
+    pub fn candidates(&self, reference: PersianDate, years: u32) -> Vec<PersianDate> {
+        let years = i32::try_from(years).unwrap_or(i32::MAX);
+        // a bound out of range is clamped to chrono's dates
+        let first = reference
+            .checked_add_years(years.saturating_neg())
+            .unwrap_or(PersianDate::from(NaiveDate::MIN));
+        let last = reference
+            .checked_add_years(years)
+            .unwrap_or(PersianDate::from(NaiveDate::MAX));
+        let months = self.month.map_or(1..=12, |m| m..=m);
+        let days = self.day.map_or(1..=31, |d| d..=d);
+        let mut dates = Vec::new();
+        for year in first.year()..=last.year() {
+            for month in months.clone() {
+                for day in days.clone() {
+                    let Some(date) = PersianDate::from_ymd_opt(year, month, day) else {
+                        continue;
+                    };
+                    if first <= date && date <= last && self.matches(date) {
+                        dates.push(date);
+                    }
+                }
+            }
+        }
+        dates.sort_by_key(|d| ((*d - reference).num_days().abs(), *d));
+        dates
+    }
+}
+
+/// a number, `None` inside when it's unreadable
+fn read_part(part: &str) -> Option<Option<u32>> {
+    if part.is_empty() {
+        return None;
+    }
+    if part.chars().any(|c| matches!(c, '_' | '?' | '*')) {
+        return Some(None);
+    }
+    part.chars()
+        .try_fold(0u32, |n, c| n.checked_mul(10)?.checked_add(digit_value(c)?))
+        .map(Some)
+}
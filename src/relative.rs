@@ -0,0 +1,220 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::Duration;
+
+use crate::format::write_number;
+#[cfg(feature = "std")]
+use crate::SystemClock;
+use crate::{CalendarDuration, Clock, Digits, PersianDate, PersianDateTime};
+
+/// A unit of a relative time, from the smallest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// 7 days
+    Week,
+    /// 30 days
+    Month,
+    /// 365 days
+    Year,
+}
+
+impl RelativeUnit {
+    const ALL: [RelativeUnit; 7] = [
+        RelativeUnit::Year,
+        RelativeUnit::Month,
+        RelativeUnit::Week,
+        RelativeUnit::Day,
+        RelativeUnit::Hour,
+        RelativeUnit::Minute,
+        RelativeUnit::Second,
+    ];
+
+    fn seconds(self) -> i64 {
+        match self {
+            RelativeUnit::Second => 1,
+            RelativeUnit::Minute => 60,
+            RelativeUnit::Hour => 3600,
+            RelativeUnit::Day => 86400,
+            RelativeUnit::Week => 7 * 86400,
+            RelativeUnit::Month => 30 * 86400,
+            RelativeUnit::Year => 365 * 86400,
+        }
+    }
+
+    /// the farsi name
+    fn name(self) -> &'static str {
+        match self {
+            RelativeUnit::Second => "ثانیه",
+            RelativeUnit::Minute => "دقیقه",
+            RelativeUnit::Hour => "ساعت",
+            RelativeUnit::Day => "روز",
+            RelativeUnit::Week => "هفته",
+            RelativeUnit::Month => "ماه",
+            RelativeUnit::Year => "سال",
+        }
+    }
+}
+
+/// How a relative time is written in farsi, such as `۳ ساعت پیش`
+///
+/// the difference is written in the largest unit it has at least one of, and differences
+/// smaller than `min_unit` are `چند لحظه پیش` (or `دیگر` in the future), `امروز` when
+/// `min_unit` is a day or more
+/// ```rust
+///use chrono::Duration;
+///use chrono_persian::{Digits, PersianDateTime, RelativeFormat, RelativeUnit};
+///
+///let now = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 12, 0, 0).unwrap();
+///let format = RelativeFormat::default();
+///assert_eq!(format.format(&(now - Duration::seconds(20)), &now), "چند لحظه پیش");
+///assert_eq!(format.format(&(now - Duration::hours(3)), &now), "۳ ساعت پیش");
+///assert_eq!(format.format(&(now + Duration::days(15)), &now), "۲ هفته دیگر");
+///
+///let latin = RelativeFormat { digits: Digits::Latin, min_unit: RelativeUnit::Day };
+///assert_eq!(latin.format(&(now - Duration::hours(3)), &now), "امروز");
+///assert_eq!(latin.format(&(now - Duration::days(400)), &now), "1 سال پیش");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativeFormat {
+    pub digits: Digits,
+    /// the smallest unit written
+    pub min_unit: RelativeUnit,
+}
+
+impl Default for RelativeFormat {
+    /// persian digits, down to minutes
+    fn default() -> Self {
+        Self {
+            digits: Digits::Persian,
+            min_unit: RelativeUnit::Minute,
+        }
+    }
+}
+
+impl RelativeFormat {
+    /// Write `value` relative to `reference`
+    pub fn format(&self, value: &PersianDateTime, reference: &PersianDateTime) -> String {
+        self.format_duration(value.signed_duration_since(*reference))
+    }
+
+    /// Write a difference, negative ones are in the past
+    pub fn format_duration(&self, difference: Duration) -> String {
+        let seconds = difference.num_seconds();
+        let direction = match seconds < 0 {
+            true => "پیش",
+            false => "دیگر",
+        };
+        let abs = seconds.unsigned_abs().min(i64::MAX as u64) as i64;
+        let unit = RelativeUnit::ALL
+            .into_iter()
+            .filter(|u| *u >= self.min_unit)
+            .find(|u| abs >= u.seconds());
+        let Some(unit) = unit else {
+            return match self.min_unit >= RelativeUnit::Day {
+                true => "امروز".to_string(),
+                false => format!("چند لحظه {direction}"),
+            };
+        };
+        let mut out = String::new();
+        let count = i32::try_from(abs / unit.seconds()).unwrap_or(i32::MAX);
+        write_number(&mut out, count, 0, self.digits).expect("writing to a string can't fail");
+        format!("{out} {} {direction}", unit.name())
+    }
+}
+
+impl PersianDateTime {
+    /// Write the time relative to now in farsi with the default [`RelativeFormat`], such as
+    /// `۲ روز پیش`
+    #[cfg(feature = "std")]
+    pub fn humanize(&self) -> String {
+        self.humanize_with(&SystemClock)
+    }
+
+    /// like [`PersianDateTime::humanize`] but with the given clock
+    /// ```rust
+    ///use chrono::{DateTime, Duration, Utc};
+    ///use chrono_persian::{FixedClock, PersianDateTime};
+    ///
+    ///let now = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let posted = PersianDateTime::from(now - Duration::days(2));
+    ///assert_eq!(posted.humanize_with(&FixedClock(now)), "۲ روز پیش");
+    /// ```
+    pub fn humanize_with<C: Clock + ?Sized>(&self, clock: &C) -> String {
+        self.to_persian_relative(&PersianDateTime::from(clock.now()))
+    }
+
+    /// Write the time relative to `reference` in farsi with the default [`RelativeFormat`]
+    pub fn to_persian_relative(&self, reference: &PersianDateTime) -> String {
+        RelativeFormat::default().format(self, reference)
+    }
+}
+
+/// The language of [`describe_difference`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Language {
+    /// with persian digits
+    #[default]
+    Farsi,
+    English,
+}
+
+/// Describe when `b` is compared to `a` in years, months and days, such as
+/// `۲ ماه و ۳ روز بعد` or `2 months and 3 days later`
+/// ```rust
+///use chrono_persian::{describe_difference, Language, PersianDate};
+///
+///let old = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+///let new = PersianDate::from_ymd_opt(1403, 10, 23).unwrap();
+///assert_eq!(describe_difference(old, new, Language::Farsi), "۲ ماه و ۳ روز بعد");
+///assert_eq!(describe_difference(new, old, Language::English), "2 months and 3 days earlier");
+///
+///let next_year = PersianDate::from_ymd_opt(1404, 8, 21).unwrap();
+///assert_eq!(describe_difference(old, next_year, Language::English), "1 year and 1 day later");
+///assert_eq!(describe_difference(old, old, Language::Farsi), "همان روز");
+///assert_eq!(describe_difference(old, old, Language::English), "the same day");
+/// ```
+pub fn describe_difference(a: PersianDate, b: PersianDate, language: Language) -> String {
+    let difference = CalendarDuration::between(a, b);
+    let units = [
+        (difference.years, "سال", "year"),
+        (difference.months, "ماه", "month"),
+        (difference.days, "روز", "day"),
+    ];
+    let mut parts = Vec::new();
+    for (count, fa, en) in units.into_iter().filter(|u| u.0 != 0) {
+        let count = i32::try_from(count).unwrap_or(i32::MAX);
+        parts.push(match language {
+            Language::Farsi => {
+                let mut out = String::new();
+                write_number(&mut out, count, 0, Digits::Persian)
+                    .expect("writing to a string can't fail");
+                format!("{out} {fa}")
+            }
+            Language::English if count == 1 => format!("1 {en}"),
+            Language::English => format!("{count} {en}s"),
+        });
+    }
+    match (language, parts.is_empty(), b > a) {
+        (Language::Farsi, true, _) => "همان روز".to_string(),
+        (Language::English, true, _) => "the same day".to_string(),
+        (Language::Farsi, false, later) => {
+            let direction = if later { "بعد" } else { "قبل" };
+            format!("{} {direction}", parts.join(" و "))
+        }
+        (Language::English, false, later) => {
+            let direction = if later { "later" } else { "earlier" };
+            let last = parts.pop().unwrap_or_default();
+            match parts.is_empty() {
+                true => format!("{last} {direction}"),
+                false => format!("{} and {last} {direction}", parts.join(", ")),
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::{
+    DateTime, Duration, FixedOffset, LocalResult, NaiveDateTime, NaiveTime, Offset, TimeZone,
+};
+
+use crate::{PersianDate, PersianDateTime, PersianError};
+
+/// How to pick an instant for a wall clock time that happened twice or never
+///
+/// this only matters with the `tz` feature, iran's clocks were moved around its historical
+/// daylight saving time transitions, without it every wall clock time is a single instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Resolve {
+    /// the first of two instants, a skipped time is moved forward by the length of the gap
+    #[default]
+    Earliest,
+    /// the second of two instants, a skipped time is moved forward by the length of the gap
+    Latest,
+    /// fail on both, with [`PersianError::AmbiguousLocalTime`] or
+    /// [`PersianError::NonexistentLocalTime`]
+    Reject,
+}
+
+/// the instant a wall clock in iran shows
+pub(crate) fn tehran_local(
+    local: NaiveDateTime,
+    resolve: Resolve,
+) -> Option<DateTime<FixedOffset>> {
+    try_tehran_local(local, resolve).ok()
+}
+
+#[cfg(not(feature = "tz"))]
+pub(crate) fn try_tehran_local(
+    local: NaiveDateTime,
+    resolve: Resolve,
+) -> Result<DateTime<FixedOffset>, PersianError> {
+    try_local_in(&crate::FIXED_OFFSET, local, resolve)
+}
+
+#[cfg(feature = "tz")]
+pub(crate) fn try_tehran_local(
+    local: NaiveDateTime,
+    resolve: Resolve,
+) -> Result<DateTime<FixedOffset>, PersianError> {
+    try_local_in(&chrono_tz::Asia::Tehran, local, resolve).map(|d| d.fixed_offset())
+}
+
+/// the instant a wall clock in `zone` shows
+pub(crate) fn local_in<Tz: TimeZone>(
+    zone: &Tz,
+    local: NaiveDateTime,
+    resolve: Resolve,
+) -> Option<DateTime<Tz>> {
+    try_local_in(zone, local, resolve).ok()
+}
+
+pub(crate) fn try_local_in<Tz: TimeZone>(
+    zone: &Tz,
+    local: NaiveDateTime,
+    resolve: Resolve,
+) -> Result<DateTime<Tz>, PersianError> {
+    match (zone.from_local_datetime(&local), resolve) {
+        (LocalResult::Single(d), _) => Ok(d),
+        (LocalResult::Ambiguous(d, _), Resolve::Earliest) => Ok(d),
+        (LocalResult::Ambiguous(_, d), Resolve::Latest) => Ok(d),
+        (LocalResult::None, Resolve::Earliest | Resolve::Latest) => {
+            // read the time with the offset from before the gap, which lands after it
+            let earlier = local
+                .checked_sub_signed(Duration::days(1))
+                .ok_or(PersianError::OutOfRange)?;
+            let before = zone.offset_from_utc_datetime(&earlier);
+            let utc = local
+                .checked_sub_signed(Duration::seconds(before.fix().local_minus_utc() as i64))
+                .ok_or(PersianError::OutOfRange)?;
+            Ok(zone.from_utc_datetime(&utc))
+        }
+        (LocalResult::Ambiguous(..), Resolve::Reject) => Err(PersianError::AmbiguousLocalTime),
+        (LocalResult::None, Resolve::Reject) => Err(PersianError::NonexistentLocalTime),
+    }
+}
+
+impl PersianDateTime {
+    /// Make the instant a wall clock in iran shows on a persian date, `None` if the time is
+    /// rejected by `resolve` or out of range
+    /// ```rust
+    ///use chrono::NaiveTime;
+    ///use chrono_persian::{PersianDate, PersianDateTime, Resolve};
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///let time = NaiveTime::from_hms_opt(2, 8, 28).unwrap();
+    ///let a = PersianDateTime::from_local(date, time, Resolve::Reject).unwrap();
+    ///assert_eq!(a.to_utc().to_string(), "2024-11-09 22:38:28 UTC");
+    /// ```
+    pub fn from_local(date: PersianDate, time: NaiveTime, resolve: Resolve) -> Option<Self> {
+        Self::try_from_local(date, time, resolve).ok()
+    }
+
+    /// Make the instant like [`PersianDateTime::from_local`], with the reason it failed
+    /// ```rust
+    ///use chrono::NaiveTime;
+    ///use chrono_persian::{PersianDate, PersianDateTime, Resolve};
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+    ///let time = NaiveTime::from_hms_opt(2, 8, 28).unwrap();
+    ///let a = PersianDateTime::try_from_local(date, time, Resolve::Reject).unwrap();
+    ///assert_eq!(a.to_utc().to_string(), "2024-11-09 22:38:28 UTC");
+    /// ```
+    pub fn try_from_local(
+        date: PersianDate,
+        time: NaiveTime,
+        resolve: Resolve,
+    ) -> Result<Self, PersianError> {
+        let local = date
+            .to_gregorian()
+            .ok_or(PersianError::OutOfRange)?
+            .and_time(time);
+        Ok(Self::from(try_tehran_local(local, resolve)?))
+    }
+}
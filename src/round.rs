@@ -0,0 +1,143 @@
+use chrono::{DateTime, Datelike, Days, FixedOffset, TimeZone};
+
+use crate::{tehran_midnight, to_tehran, PeriodScheme, PersianDate};
+
+/// A calendar unit of the persian calendar, boundaries are taken in iran's time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PersianUnit {
+    /// from midnight to midnight
+    Day,
+    /// from saturday to friday
+    Week,
+    /// from the first to the last day of a persian month
+    Month,
+    /// three months, the quarters start at farvardin, tir, mehr and dey
+    Quarter,
+    /// from nowruz to the last day of esfand
+    Year,
+    /// the periods of a scheme, such as fiscal years starting at mehr
+    Scheme(PeriodScheme),
+}
+
+impl PersianUnit {
+    /// the first day of the period containing `date`
+    pub(crate) fn trunc(self, date: PersianDate) -> Option<PersianDate> {
+        match self {
+            PersianUnit::Day => Some(date),
+            PersianUnit::Week => {
+                let g = date.to_gregorian()?;
+                let since_saturday = (g.weekday().num_days_from_sunday() + 1) % 7;
+                Some(PersianDate::from(g - Days::new(since_saturday as u64)))
+            }
+            PersianUnit::Month => Some(PersianDate::from_ymd_unchecked(
+                date.year(),
+                date.month(),
+                1,
+            )),
+            PersianUnit::Quarter => Some(PersianDate::from_ymd_unchecked(
+                date.year(),
+                (date.month() - 1) / 3 * 3 + 1,
+                1,
+            )),
+            PersianUnit::Year => Some(PersianDate::from_ymd_unchecked(date.year(), 1, 1)),
+            PersianUnit::Scheme(scheme) => scheme.period_of(date).first_day(),
+        }
+    }
+
+    /// the first day of the period after the one starting at `start`
+    pub(crate) fn next(self, start: PersianDate) -> Option<PersianDate> {
+        match self {
+            PersianUnit::Day => Some(PersianDate::from(
+                start.to_gregorian()?.checked_add_days(Days::new(1))?,
+            )),
+            PersianUnit::Week => Some(PersianDate::from(
+                start.to_gregorian()?.checked_add_days(Days::new(7))?,
+            )),
+            PersianUnit::Month if start.month() == 12 => {
+                Some(PersianDate::from_ymd_unchecked(start.year() + 1, 1, 1))
+            }
+            PersianUnit::Month => Some(PersianDate::from_ymd_unchecked(
+                start.year(),
+                start.month() + 1,
+                1,
+            )),
+            PersianUnit::Quarter if start.month() > 9 => {
+                Some(PersianDate::from_ymd_unchecked(start.year() + 1, 1, 1))
+            }
+            PersianUnit::Quarter => Some(PersianDate::from_ymd_unchecked(
+                start.year(),
+                start.month() + 3,
+                1,
+            )),
+            PersianUnit::Year => Some(PersianDate::from_ymd_unchecked(start.year() + 1, 1, 1)),
+            PersianUnit::Scheme(scheme) => scheme.period_of(start).succ()?.first_day(),
+        }
+    }
+
+    /// the `[start, end)` instants of the period containing `instant`
+    pub(crate) fn bounds<Tz: TimeZone>(
+        self,
+        instant: &DateTime<Tz>,
+    ) -> Option<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+        let local = to_tehran(instant);
+        let start = self.trunc(PersianDate::from(local.date_naive()))?;
+        let end = self.next(start)?;
+        Some((
+            tehran_midnight(start.to_gregorian()?)?,
+            tehran_midnight(end.to_gregorian()?)?,
+        ))
+    }
+}
+
+/// Round or truncate chrono datetimes to persian calendar units, like chrono's `DurationRound`
+pub trait PersianDurationRound: Sized {
+    /// Round to the nearest boundary of `unit`, halfway points round up
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::{PersianDurationRound, PersianUnit};
+    ///
+    /// // 1403-08-20 18:00 in tehran
+    ///let utc = "2024-11-10 14:30:00 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let a = utc.duration_round_persian(PersianUnit::Day).unwrap();
+    ///assert_eq!(a.to_string(), "2024-11-10 20:30:00 UTC");
+    /// ```
+    fn duration_round_persian(self, unit: PersianUnit) -> Option<Self>;
+
+    /// Truncate to the start of the `unit` containing the datetime
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::{PeriodScheme, PersianDurationRound, PersianUnit};
+    ///
+    /// // 1403-08-20 in tehran, a sunday
+    ///let utc = "2024-11-10 14:30:00 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let week = utc.duration_trunc_persian(PersianUnit::Week).unwrap();
+    ///assert_eq!(week.to_string(), "2024-11-08 20:30:00 UTC");
+    ///
+    ///let month = utc.duration_trunc_persian(PersianUnit::Month).unwrap();
+    ///assert_eq!(month.to_string(), "2024-10-21 20:30:00 UTC");
+    ///
+    /// // 1403-07-01, the fiscal year starts at mehr
+    ///let fiscal = PersianUnit::Scheme(PeriodScheme::fiscal_year(7));
+    ///let year = utc.duration_trunc_persian(fiscal).unwrap();
+    ///assert_eq!(year.to_string(), "2024-09-21 20:30:00 UTC");
+    /// ```
+    fn duration_trunc_persian(self, unit: PersianUnit) -> Option<Self>;
+}
+
+impl<Tz: TimeZone> PersianDurationRound for DateTime<Tz> {
+    fn duration_round_persian(self, unit: PersianUnit) -> Option<Self> {
+        let (start, end) = unit.bounds(&self)?;
+        let nearest =
+            if self.clone().signed_duration_since(start) < end.signed_duration_since(&self) {
+                start
+            } else {
+                end
+            };
+        Some(nearest.with_timezone(&self.timezone()))
+    }
+
+    fn duration_trunc_persian(self, unit: PersianUnit) -> Option<Self> {
+        let (start, _) = unit.bounds(&self)?;
+        Some(start.with_timezone(&self.timezone()))
+    }
+}
@@ -0,0 +1,266 @@
+use alloc::vec::Vec;
+use chrono::{DateTime, Utc};
+use core::fmt;
+use core::ops::Range;
+
+use crate::{
+    calendar, tehran_midnight, PersianDate, PersianDateRange, PersianPeriod, PersianQuarter,
+};
+
+/// A way of dividing years into periods of equal months, such as a fiscal year starting at
+/// mehr or half-years
+///
+/// a year of the scheme is named by the persian year it starts in
+/// ```rust
+///use chrono_persian::{PeriodScheme, PersianDate};
+///
+///let fiscal = PeriodScheme::new(7, 3).unwrap();
+///let date = PersianDate::from_ymd_opt(1404, 2, 10).unwrap();
+///let period = fiscal.period_of(date);
+///assert_eq!((period.year(), period.index()), (1403, 3));
+///assert_eq!(period.first_day().unwrap().to_string(), "1404-01-01");
+///assert_eq!(period.to_string(), "Q3 1403-1404");
+///
+///let half = PeriodScheme::HALF_YEAR.period_of(date);
+///assert_eq!(half.to_string(), "H1 1404");
+///assert_eq!(half.last_day().unwrap().to_string(), "1404-06-31");
+///
+///assert!(PeriodScheme::new(7, 5).is_none());
+///assert!(fiscal.period(i32::MAX, 1).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PeriodScheme {
+    start_month: u32,
+    months: u32,
+}
+
+impl PeriodScheme {
+    /// the persian year, from farvardin
+    pub const YEAR: Self = Self::fiscal_year(1);
+    /// two halves, from farvardin and mehr
+    pub const HALF_YEAR: Self = Self {
+        start_month: 1,
+        months: 6,
+    };
+    /// four quarters, from farvardin, tir, mehr and dey
+    pub const QUARTER: Self = Self {
+        start_month: 1,
+        months: 3,
+    };
+    /// the persian months
+    pub const MONTH: Self = Self {
+        start_month: 1,
+        months: 1,
+    };
+
+    /// Make a scheme whose years start at `start_month` and are split into periods of
+    /// `months` months, `None` if the month isn't from 1 to 12 or `months` doesn't divide 12
+    pub const fn new(start_month: u32, months: u32) -> Option<Self> {
+        match (start_month, months) {
+            (1..=12, 1 | 2 | 3 | 4 | 6 | 12) => Some(Self {
+                start_month,
+                months,
+            }),
+            _ => None,
+        }
+    }
+
+    /// A scheme of whole years starting at `start_month`, which must be from 1 to 12
+    pub const fn fiscal_year(start_month: u32) -> Self {
+        assert!(1 <= start_month && start_month <= 12, "month out of range");
+        Self {
+            start_month,
+            months: 12,
+        }
+    }
+
+    /// the month the years of the scheme start at
+    pub fn start_month(&self) -> u32 {
+        self.start_month
+    }
+
+    /// the months in each period
+    pub fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// the number of periods in a year
+    pub fn periods_per_year(&self) -> u32 {
+        12 / self.months
+    }
+
+    /// Get the period of the scheme containing `date`
+    pub fn period_of(&self, date: PersianDate) -> SchemePeriod {
+        let since_start = (date.month() + 12 - self.start_month) % 12;
+        let year = match date.month() >= self.start_month {
+            true => date.year(),
+            false => date.year() - 1,
+        };
+        SchemePeriod {
+            scheme: *self,
+            year,
+            index: since_start / self.months + 1,
+        }
+    }
+
+    /// Get a period by its year and index, `None` if the index is out of range or a day of
+    /// the period is
+    pub fn period(&self, year: i32, index: u32) -> Option<SchemePeriod> {
+        if !(1..=self.periods_per_year()).contains(&index) {
+            return None;
+        }
+        let period = SchemePeriod {
+            scheme: *self,
+            year,
+            index,
+        };
+        period.last_day()?;
+        period.first_day().map(|_| period)
+    }
+}
+
+/// One period of a [`PeriodScheme`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SchemePeriod {
+    scheme: PeriodScheme,
+    year: i32,
+    index: u32,
+}
+
+impl SchemePeriod {
+    pub fn scheme(&self) -> PeriodScheme {
+        self.scheme
+    }
+
+    /// the year of the scheme, the persian year it starts in
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// the position of the period in its year, starting from 1
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// the year and month a month of the period falls in, from 0
+    fn month_at(&self, offset: u32) -> Option<(i32, u32)> {
+        let m = self.scheme.start_month - 1 + (self.index - 1) * self.scheme.months + offset;
+        Some((self.year.checked_add((m / 12) as i32)?, m % 12 + 1))
+    }
+
+    /// the first day of the period, `None` when out of range, which only a period of
+    /// [`PeriodScheme::period_of`] at the edge of the range can be
+    pub fn first_day(&self) -> Option<PersianDate> {
+        let (year, month) = self.month_at(0)?;
+        PersianDate::from_ymd_opt(year, month, 1)
+    }
+
+    /// the last day of the period, `None` when out of range like [`SchemePeriod::first_day`]
+    pub fn last_day(&self) -> Option<PersianDate> {
+        let (year, month) = self.month_at(self.scheme.months - 1)?;
+        PersianDate::from_ymd_opt(year, month, calendar::days_in_month(year, month))
+    }
+
+    /// the days of the period, from its first to its last day, `None` when out of range
+    pub fn to_range(&self) -> Option<PersianDateRange> {
+        PersianDateRange::new(self.first_day()?, self.last_day()?)
+    }
+
+    /// the next period of the scheme, `None` when out of range
+    pub fn succ(&self) -> Option<Self> {
+        match self.index == self.scheme.periods_per_year() {
+            true => self.scheme.period(self.year.checked_add(1)?, 1),
+            false => self.scheme.period(self.year, self.index + 1),
+        }
+    }
+
+    /// the previous period of the scheme, `None` when out of range
+    pub fn pred(&self) -> Option<Self> {
+        match self.index {
+            1 => self
+                .scheme
+                .period(self.year.checked_sub(1)?, self.scheme.periods_per_year()),
+            _ => self.scheme.period(self.year, self.index - 1),
+        }
+    }
+
+    /// Get the `[start, end)` utc instants of the period in iran's time, like
+    /// [`crate::PersianPeriod::utc_range`]
+    pub fn utc_range(&self) -> Option<Range<DateTime<Utc>>> {
+        let end = self.succ()?.first_day()?;
+        Some(
+            tehran_midnight(self.first_day()?.to_gregorian()?)?.to_utc()
+                ..tehran_midnight(end.to_gregorian()?)?.to_utc(),
+        )
+    }
+}
+
+impl From<PersianQuarter> for SchemePeriod {
+    fn from(value: PersianQuarter) -> Self {
+        SchemePeriod {
+            scheme: PeriodScheme::QUARTER,
+            year: value.year(),
+            index: value.quarter(),
+        }
+    }
+}
+
+impl From<SchemePeriod> for PersianPeriod {
+    fn from(value: SchemePeriod) -> Self {
+        PersianPeriod::Scheme(value)
+    }
+}
+
+/// `1403`, `1403-1404` when the years start after farvardin, with `H1`, `Q1`, `M1` or `P1`
+/// before it for the periods of shorter schemes
+impl fmt::Display for SchemePeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match self.scheme.months {
+            12 => None,
+            6 => Some('H'),
+            3 => Some('Q'),
+            1 => Some('M'),
+            _ => Some('P'),
+        };
+        if let Some(prefix) = prefix {
+            write!(f, "{prefix}{} ", self.index)?;
+        }
+        match self.scheme.start_month {
+            1 => write!(f, "{}", self.year),
+            _ => write!(f, "{}-{}", self.year, i64::from(self.year) + 1),
+        }
+    }
+}
+
+impl PersianDateRange {
+    /// Split the range at the period boundaries of `scheme`, with the part of the range in
+    /// each period
+    /// ```rust
+    ///use chrono_persian::{PeriodScheme, PersianDate, PersianDateRange};
+    ///
+    ///let range = PersianDateRange::new(
+    ///    PersianDate::from_ymd_opt(1403, 5, 10).unwrap(),
+    ///    PersianDate::from_ymd_opt(1403, 8, 1).unwrap(),
+    ///)
+    ///.unwrap();
+    ///let parts = range.split_by_scheme(PeriodScheme::fiscal_year(7));
+    ///let labels: Vec<_> = parts.iter().map(|(p, r)| format!("{p}: {}", r.start())).collect();
+    ///assert_eq!(labels, ["1402-1403: 1403-05-10", "1403-1404: 1403-07-01"]);
+    /// ```
+    pub fn split_by_scheme(&self, scheme: PeriodScheme) -> Vec<(SchemePeriod, PersianDateRange)> {
+        let mut parts = Vec::new();
+        let mut period = scheme.period_of(self.start());
+        loop {
+            // the days out of range are never in the range
+            let start = period
+                .first_day()
+                .map_or(self.start(), |d| d.max(self.start()));
+            let end = period.last_day().map_or(self.end(), |d| d.min(self.end()));
+            parts.push((period, PersianDateRange::new(start, end).unwrap_or(*self)));
+            match period.succ() {
+                Some(next) if end != self.end() => period = next,
+                _ => return parts,
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Serde support (requires the `serde` feature)
+//!
+//! [`PersianDate`] is (de)serialized as a `year-month-day` string,
+//! a [`PersianDateTime`] as `year-month-dayThh:mm:ss` followed by the offset of its wall clock,
+//! a [`PersianDateRange`] as a `{ "from": .., "to": .. }` map, and [`DualDateTime`] writes an
+//! instant in both calendars
+//! ```rust
+//!use chrono::{DateTime, FixedOffset, Utc};
+//!use chrono_persian::{PersianDate, PersianDateRange, PersianDateTime};
+//!
+//!let date: PersianDate = serde_json::from_str(r#""1403/08/20""#).unwrap();
+//!assert_eq!(serde_json::to_string(&date).unwrap(), r#""1403-08-20""#);
+//!
+//!let range: PersianDateRange = serde_json::from_str(r#"{"from":"1403/01/01","to":"1403/06/31"}"#).unwrap();
+//!assert_eq!(range.end().to_string(), "1403-06-31");
+//!
+//!assert!(serde_json::from_str::<PersianDateRange>(r#"{"from":"1403/06/31","to":"1403/01/01"}"#).is_err());
+//!
+//!let utc = "2024-11-09 22:38:28.5 UTC".parse::<DateTime<Utc>>().unwrap();
+//!let kabul = PersianDateTime::from(utc).with_offset(FixedOffset::east_opt(16200).unwrap());
+//!let json = serde_json::to_string(&kabul).unwrap();
+//!assert_eq!(json, r#""1403-08-20T03:08:28.500+04:30""#);
+//!
+//!let back: PersianDateTime = serde_json::from_str(&json).unwrap();
+//!assert_eq!(back, kabul);
+//!assert_eq!(back.offset(), kabul.offset());
+//! ```
+
+mod dual;
+#[cfg(feature = "derive")]
+pub mod format;
+pub mod jalali;
+
+pub use dual::DualDateTime;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::ser::{SerializeStruct, Serializer};
+use ::serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{PersianDate, PersianDateRange, PersianDateTime};
+
+impl Serialize for PersianDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PersianDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DateVisitor;
+
+        impl Visitor<'_> for DateVisitor {
+            type Value = PersianDate;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a jalali date such as 1403/08/20")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<PersianDate, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+impl Serialize for PersianDateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_rfc3339())
+    }
+}
+
+impl<'de> Deserialize<'de> for PersianDateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DateTimeVisitor;
+
+        impl Visitor<'_> for DateTimeVisitor {
+            type Value = PersianDateTime;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a jalali date and time such as 1403-08-20T02:08:28+03:30")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<PersianDateTime, E> {
+                PersianDateTime::parse_from_rfc3339(v)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(DateTimeVisitor)
+    }
+}
+
+impl Serialize for PersianDateRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("PersianDateRange", 2)?;
+        s.serialize_field("from", &self.start())?;
+        s.serialize_field("to", &self.end())?;
+        s.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for PersianDateRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(crate = "::serde")]
+        struct Range {
+            from: PersianDate,
+            to: PersianDate,
+        }
+
+        let range = Range::deserialize(deserializer)?;
+        PersianDateRange::new(range.from, range.to)
+            .ok_or_else(|| de::Error::custom("`from` must not be after `to`"))
+    }
+}
@@ -0,0 +1,87 @@
+use ::serde::de::{Deserializer, Error as _};
+use ::serde::ser::{SerializeStruct, Serializer};
+use ::serde::{Deserialize, Serialize};
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{FromPersian, PersianDateTime};
+
+/// An instant (de)serialized as a map with both its `gregorian` and `jalali` forms
+///
+/// `gregorian` is chrono's rfc 3339 form in utc and `jalali` is a [`PersianDateTime`] in iran's
+/// time, either of them is enough to deserialize, when both are given they must be the same
+/// instant
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::serde::DualDateTime;
+///
+///let now = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let json = serde_json::to_string(&DualDateTime(now)).unwrap();
+///assert_eq!(json, r#"{"gregorian":"2024-11-09T22:38:28Z","jalali":"1403-08-20T02:08:28+03:30"}"#);
+///
+///let from_jalali: DualDateTime = serde_json::from_str(r#"{"jalali":"1403-08-20T02:08:28+03:30"}"#).unwrap();
+///assert_eq!(from_jalali.0, now);
+///let from_gregorian: DualDateTime = serde_json::from_str(r#"{"gregorian":"2024-11-10T02:08:28+03:30"}"#).unwrap();
+///assert_eq!(from_gregorian.0, now);
+///
+///let mismatch = r#"{"gregorian":"2024-11-09T22:38:28Z","jalali":"1403-08-20T02:08:29+03:30"}"#;
+///assert!(serde_json::from_str::<DualDateTime>(mismatch).is_err());
+///assert!(serde_json::from_str::<DualDateTime>("{}").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DualDateTime(pub DateTime<Utc>);
+
+impl From<DateTime<Utc>> for DualDateTime {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DualDateTime> for DateTime<Utc> {
+    fn from(value: DualDateTime) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for DualDateTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("DualDateTime", 2)?;
+        s.serialize_field(
+            "gregorian",
+            &self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        )?;
+        s.serialize_field("jalali", &PersianDateTime::from(self.0))?;
+        s.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DualDateTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(crate = "::serde")]
+        struct Dual {
+            #[serde(default)]
+            gregorian: Option<String>,
+            #[serde(default)]
+            jalali: Option<PersianDateTime>,
+        }
+
+        let dual = Dual::deserialize(deserializer)?;
+        let gregorian = dual
+            .gregorian
+            .map(|g| DateTime::parse_from_rfc3339(&g).map(|g| g.to_utc()))
+            .transpose()
+            .map_err(D::Error::custom)?;
+        let jalali = dual
+            .jalali
+            .map(|j| DateTime::<Utc>::from_persian(&j))
+            .map(|j| j.ok_or_else(|| D::Error::custom("datetime out of range")))
+            .transpose()?;
+        match (gregorian, jalali) {
+            (Some(g), Some(j)) if g != j => Err(D::Error::custom(
+                "`gregorian` and `jalali` are different instants",
+            )),
+            (Some(value), _) | (None, Some(value)) => Ok(Self(value)),
+            (None, None) => Err(D::Error::custom("expected `gregorian` or `jalali`")),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Runtime of the [`persian_serde`](crate::persian_serde) attribute (requires the `derive` feature)
+//!
+//! fields marked with `#[persian(...)]` are (de)serialized as jalali strings, their type must
+//! implement [`PersianField`]. `format` is a strftime-like pattern (see [`crate::CompiledFormat`]) and
+//! `digits` is either `"en"` (the default) or `"fa"`, parsing accepts both kinds of digits
+//! ```rust
+//!use chrono::{DateTime, NaiveDate, Utc};
+//!use chrono_persian::persian_serde;
+//!use serde::{Deserialize, Serialize};
+//!
+//!#[persian_serde]
+//!#[derive(Serialize, Deserialize, Debug, PartialEq)]
+//!struct Invoice {
+//!    #[persian(format = "%Y/%m/%d", digits = "fa")]
+//!    issued: NaiveDate,
+//!    #[persian]
+//!    paid_at: Option<DateTime<Utc>>,
+//!}
+//!
+//!let invoice = Invoice {
+//!    issued: NaiveDate::from_ymd_opt(2024, 11, 10).unwrap(),
+//!    paid_at: Some("2024-11-09 22:38:28 UTC".parse().unwrap()),
+//!};
+//!let json = serde_json::to_string(&invoice).unwrap();
+//!assert_eq!(json, r#"{"issued":"۱۴۰۳/۰۸/۲۰","paid_at":"1403-08-20 02:08:28"}"#);
+//!assert_eq!(serde_json::from_str::<Invoice>(&json).unwrap(), invoice);
+//!
+//! // a missing optional field is `None`
+//!let unpaid: Invoice = serde_json::from_str(r#"{"issued":"1403/08/20"}"#).unwrap();
+//!assert_eq!(unpaid.paid_at, None);
+//! ```
+//! generic structs work too, the fields' types must implement [`PersianField`]
+//! ```rust
+//!use chrono::NaiveDate;
+//!use chrono_persian::{persian_serde, PersianField};
+//!use serde::{Deserialize, Serialize};
+//!
+//!#[persian_serde]
+//!#[derive(Serialize, Deserialize)]
+//!struct Event<'a, T>
+//!where
+//!    T: PersianField,
+//!{
+//!    name: &'a str,
+//!    #[persian(format = "%Y/%m/%d")]
+//!    day: T,
+//!    #[persian]
+//!    moved_to: Option<T>,
+//!}
+//!
+//!let event = Event {
+//!    name: "nowruz",
+//!    day: NaiveDate::from_ymd_opt(2025, 3, 21).unwrap(),
+//!    moved_to: None,
+//!};
+//!let json = serde_json::to_string(&event).unwrap();
+//!assert_eq!(json, r#"{"name":"nowruz","day":"1404/01/01","moved_to":null}"#);
+//! ```
+
+use ::serde::de::{Deserialize, Deserializer, Error as _};
+use ::serde::ser::{Error as _, Serializer};
+
+use crate::format::{compile, parse_items, write_items};
+use crate::{Digits, PersianField};
+
+fn to_string<T: PersianField>(
+    value: &T,
+    format: Option<&str>,
+    digits: Digits,
+) -> Result<String, crate::PersianError> {
+    let items = compile(format.unwrap_or(T::DEFAULT_FORMAT))?;
+    let (date, time) = value.to_persian_fields();
+    let mut out = String::new();
+    write_items(&mut out, &items, date, time, digits)?;
+    Ok(out)
+}
+
+fn from_str<T: PersianField>(s: &str, format: Option<&str>) -> Result<T, crate::PersianError> {
+    let items = compile(format.unwrap_or(T::DEFAULT_FORMAT))?;
+    let (date, time) = parse_items(&items, s)?;
+    T::from_persian_fields(date, time).ok_or(crate::PersianError::InvalidFormat)
+}
+
+/// Serialize a field as a jalali string
+pub fn serialize<T: PersianField, S: Serializer>(
+    value: &T,
+    format: Option<&str>,
+    digits: Digits,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&to_string(value, format, digits).map_err(S::Error::custom)?)
+}
+
+/// Deserialize a field from a jalali string
+///
+/// a leap second (`:60`) is accepted and kept the way chrono keeps it
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::persian_serde;
+///use serde::Deserialize;
+///
+///#[persian_serde]
+///#[derive(Deserialize)]
+///struct Stamp {
+///    #[persian]
+///    at: DateTime<Utc>,
+///}
+///
+///let stamp: Stamp = serde_json::from_str(r#"{"at":"1395-10-12 03:29:60"}"#).unwrap();
+///assert_eq!(stamp.at.to_rfc3339(), "2016-12-31T23:59:60+00:00");
+/// ```
+pub fn deserialize<'de, T: PersianField, D: Deserializer<'de>>(
+    format: Option<&str>,
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    from_str(&s, format).map_err(D::Error::custom)
+}
+
+/// Like [`serialize`] for an optional field, `None` is serialized as none
+pub fn serialize_option<T: PersianField, S: Serializer>(
+    value: &Option<T>,
+    format: Option<&str>,
+    digits: Digits,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serialize(value, format, digits, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Like [`deserialize`] for an optional field
+pub fn deserialize_option<'de, T: PersianField, D: Deserializer<'de>>(
+    format: Option<&str>,
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|s| from_str(&s, format).map_err(D::Error::custom))
+        .transpose()
+}
@@ -0,0 +1,72 @@
+//! (De)serialize chrono datetimes as jalali strings with `#[serde(with = "...")]`
+//!
+//! the value is written like a [`PersianDateTime`], `1403-08-20T02:08:28+03:30` in iran's time,
+//! and read back into any type implementing [`FromPersian`]
+//! ```rust
+//!use chrono::{DateTime, Utc};
+//!use serde::{Deserialize, Serialize};
+//!
+//!#[derive(Serialize, Deserialize, Debug, PartialEq)]
+//!struct Order {
+//!    #[serde(with = "chrono_persian::serde::jalali")]
+//!    created_at: DateTime<Utc>,
+//!    #[serde(with = "chrono_persian::serde::jalali::option")]
+//!    shipped_at: Option<DateTime<Utc>>,
+//!}
+//!
+//!let order = Order {
+//!    created_at: "2024-11-09 22:38:28 UTC".parse().unwrap(),
+//!    shipped_at: None,
+//!};
+//!let json = serde_json::to_string(&order).unwrap();
+//!assert_eq!(json, r#"{"created_at":"1403-08-20T02:08:28+03:30","shipped_at":null}"#);
+//!assert_eq!(serde_json::from_str::<Order>(&json).unwrap(), order);
+//! ```
+
+use ::serde::de::{Deserialize, Deserializer, Error as _};
+use ::serde::ser::{Serialize, Serializer};
+use chrono::{DateTime, TimeZone};
+
+use crate::{FromPersian, PersianDateTime};
+
+/// Serialize an instant as the jalali date and time in iran
+pub fn serialize<Tz: TimeZone, S: Serializer>(
+    value: &DateTime<Tz>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    PersianDateTime::from(value.clone()).serialize(serializer)
+}
+
+/// Deserialize an instant from a jalali date and time
+pub fn deserialize<'de, T: FromPersian, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let value = PersianDateTime::deserialize(deserializer)?;
+    T::from_persian(&value).ok_or_else(|| D::Error::custom("datetime out of range"))
+}
+
+/// Like [`super::jalali`] for optional instants, `None` is serialized as none
+pub mod option {
+    use super::*;
+
+    pub fn serialize<Tz: TimeZone, S: Serializer>(
+        value: &Option<DateTime<Tz>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&PersianDateTime::from(value.clone())),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T: FromPersian, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        match Option::<PersianDateTime>::deserialize(deserializer)? {
+            Some(value) => T::from_persian(&value)
+                .map(Some)
+                .ok_or_else(|| D::Error::custom("datetime out of range")),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+use crate::{CompiledFormat, Digits, PersianDateTime};
+
+type Subscriber = Box<dyn FnMut(&str)>;
+
+/// A formatted datetime that tells its subscribers when its text changes, for reactive
+/// frontends such as rust apps compiled to wasm
+///
+/// the value, the pattern and the digits (such as from `locale::digits_for` with the `locale`
+/// feature) can each be set, the text is only rewritten and the subscribers only called when
+/// it comes out different
+/// ```rust
+///use chrono::Duration;
+///use chrono_persian::{CompiledFormat, Digits, PersianDateSignal, PersianDateTime};
+///use std::cell::RefCell;
+///use std::rc::Rc;
+///
+///let now = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 10, 0, 0).unwrap();
+///let mut signal = PersianDateSignal::new(now, CompiledFormat::new("%d %B").unwrap());
+///assert_eq!(signal.get(), "20 آبان");
+///
+///let seen = Rc::new(RefCell::new(Vec::new()));
+///let log = seen.clone();
+///signal.subscribe(move |text| log.borrow_mut().push(text.to_string()));
+///
+///signal.set(now + Duration::hours(1));
+///signal.set_digits(Digits::Persian);
+///signal.set(now + Duration::days(1));
+///assert_eq!(*seen.borrow(), ["۲۰ آبان", "۲۱ آبان"]);
+/// ```
+pub struct PersianDateSignal {
+    value: PersianDateTime,
+    format: CompiledFormat,
+    text: String,
+    subscribers: Vec<Subscriber>,
+}
+
+impl PersianDateSignal {
+    pub fn new(value: PersianDateTime, format: CompiledFormat) -> Self {
+        Self {
+            text: format.format(&value),
+            value,
+            format,
+            subscribers: Vec::new(),
+        }
+    }
+
+    /// the current text
+    pub fn get(&self) -> &str {
+        &self.text
+    }
+
+    pub fn value(&self) -> PersianDateTime {
+        self.value
+    }
+
+    /// Call `f` with the new text every time it changes
+    pub fn subscribe(&mut self, f: impl FnMut(&str) + 'static) {
+        self.subscribers.push(Box::new(f));
+    }
+
+    pub fn set(&mut self, value: PersianDateTime) {
+        self.value = value;
+        self.update();
+    }
+
+    pub fn set_format(&mut self, format: CompiledFormat) {
+        self.format = format;
+        self.update();
+    }
+
+    pub fn set_digits(&mut self, digits: Digits) {
+        self.format = self.format.clone().with_digits(digits);
+        self.update();
+    }
+
+    fn update(&mut self) {
+        let text = self.format.format(&self.value);
+        if text == self.text {
+            return;
+        }
+        self.text = text;
+        for subscriber in &mut self.subscribers {
+            subscriber(&self.text);
+        }
+    }
+}
+
+impl fmt::Debug for PersianDateSignal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PersianDateSignal")
+            .field("value", &self.value)
+            .field("format", &self.format)
+            .field("text", &self.text)
+            .field("subscribers", &self.subscribers.len())
+            .finish()
+    }
+}
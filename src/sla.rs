@@ -0,0 +1,114 @@
+use chrono::{Duration, NaiveTime};
+use core::ops::Range;
+
+use crate::{BusinessCalendar, PersianDate, PersianDateTime, Resolve};
+
+/// the first instant of `date` in iran
+fn start_of(date: PersianDate) -> Option<PersianDateTime> {
+    PersianDateTime::from_local(date, NaiveTime::MIN, Resolve::Earliest)
+}
+
+/// the instants `calendar` is open on `date`, `None` if it's closed
+fn open_on(calendar: &BusinessCalendar, date: PersianDate) -> Option<Range<PersianDateTime>> {
+    if !calendar.is_business_day(date) {
+        return None;
+    }
+    match calendar.working_hours(date.weekday()) {
+        Some(hours) => Some(
+            PersianDateTime::from_local(date, hours.start, Resolve::Earliest)?
+                ..PersianDateTime::from_local(date, hours.end, Resolve::Latest)?,
+        ),
+        None => Some(start_of(date)?..start_of(date.succ_opt()?)?),
+    }
+}
+
+/// Get when `duration` of business time has passed since `start`, the clock stops on the days
+/// `calendar` is closed and outside its [`BusinessCalendar::working_hours`], in iran's time
+///
+/// `None` if the calendar is closed for a whole year on the way or the result is out of range
+/// ```rust
+///use chrono::{Duration, NaiveTime};
+///use chrono_persian::holidays::official_calendar;
+///use chrono_persian::{sla_deadline, PersianDateTime, Weekend};
+///
+///let calendar = official_calendar();
+/// // a wednesday afternoon, thursday and friday are the weekend
+///let opened = PersianDateTime::from_ymd_hms_opt(1403, 8, 23, 18, 0, 0).unwrap();
+///let due = sla_deadline(&opened, Duration::hours(8), &calendar).unwrap();
+///assert_eq!(due.to_string(), "1403-08-26 02:00:00 +03:30");
+///
+///let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+///let workdays: Vec<_> = Weekend::THURSDAY_FRIDAY.workdays().collect();
+///let office = calendar.with_working_hours(&workdays, time(8)..time(16));
+///let due = sla_deadline(&opened, Duration::hours(8), &office).unwrap();
+///assert_eq!(due.to_string(), "1403-08-26 16:00:00 +03:30");
+/// ```
+pub fn sla_deadline(
+    start: &PersianDateTime,
+    duration: Duration,
+    calendar: &BusinessCalendar,
+) -> Option<PersianDateTime> {
+    let mut at = *start;
+    let mut remaining = duration;
+    let mut closed = 0;
+    loop {
+        let next = start_of(at.date().succ_opt()?)?;
+        match open_on(calendar, at.date()) {
+            Some(open) if open.end > at => {
+                closed = 0;
+                let from = open.start.max(at);
+                if remaining <= open.end - from {
+                    return Some(from + remaining);
+                }
+                remaining -= open.end - from;
+            }
+            _ => {
+                closed += 1;
+                if closed > 366 {
+                    return None;
+                }
+            }
+        }
+        at = next;
+    }
+}
+
+/// The business time from `start` to `end`, the time `calendar` is open in iran, negative when
+/// `end` is before `start`
+/// ```rust
+///use chrono_persian::holidays::official_calendar;
+///use chrono_persian::{business_hours_between, PersianDateTime};
+///
+///let calendar = official_calendar();
+///let opened = PersianDateTime::from_ymd_hms_opt(1403, 8, 23, 18, 0, 0).unwrap();
+///let closed = PersianDateTime::from_ymd_hms_opt(1403, 8, 26, 2, 0, 0).unwrap();
+///assert_eq!(business_hours_between(&opened, &closed, &calendar).num_hours(), 8);
+///assert_eq!(business_hours_between(&closed, &opened, &calendar).num_hours(), -8);
+/// ```
+pub fn business_hours_between(
+    start: &PersianDateTime,
+    end: &PersianDateTime,
+    calendar: &BusinessCalendar,
+) -> Duration {
+    let (from, to, sign) = match start <= end {
+        true => (*start, *end, 1),
+        false => (*end, *start, -1),
+    };
+    let mut total = Duration::zero();
+    let mut at = from;
+    while at < to {
+        let next = at
+            .date()
+            .succ_opt()
+            .and_then(start_of)
+            .map_or(to, |n| n.min(to));
+        if let Some(open) = open_on(calendar, at.date()) {
+            let (from, until) = (open.start.max(at), open.end.min(next));
+            if from < until {
+                total += until - from;
+            }
+        }
+        at = next;
+    }
+    total * sign
+}
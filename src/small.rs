@@ -0,0 +1,181 @@
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+use core::fmt::{self, Write};
+use core::ops::Deref;
+
+use chrono_persian_core::{Fields, FormatFields};
+
+use crate::{CompiledFormat, PersianDate, PersianDateTime, PersianError};
+
+/// bytes a [`SmallStr`] can hold, enough for any pattern of a few fields
+const CAPACITY: usize = 64;
+
+/// A short string stored inline, without allocating
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::{CompiledFormat, PersianDateTime};
+///
+///let format = CompiledFormat::new("%Y-%m-%d %H:%M:%S").unwrap();
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let a = format.format_to_smallstr(&PersianDateTime::from(utc)).unwrap();
+///assert_eq!(&*a, "1403-08-20 02:08:28");
+/// ```
+#[derive(Clone, Copy)]
+pub struct SmallStr {
+    buf: [u8; CAPACITY],
+    len: usize,
+}
+
+impl SmallStr {
+    /// the most bytes it can hold
+    pub const CAPACITY: usize = CAPACITY;
+
+    pub(crate) fn new() -> Self {
+        Self {
+            buf: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).expect("only whole `str`s are copied in")
+    }
+}
+
+impl Write for SmallStr {
+    /// fails when the string doesn't fit
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > CAPACITY {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl Deref for SmallStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for SmallStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for SmallStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SmallStr {}
+
+impl PartialEq<str> for SmallStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for SmallStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl fmt::Debug for SmallStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for SmallStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// reused by [`CompiledFormat::format_with`], so formatting doesn't allocate once it has grown
+    static SCRATCH: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+impl CompiledFormat {
+    /// Format a datetime without allocating, fails with [`PersianError::OutputTooLong`] if the
+    /// result is longer than [`SmallStr::CAPACITY`] bytes
+    /// ```rust
+    ///use chrono_persian::{CompiledFormat, PersianDateTime, PersianError};
+    ///
+    ///let value = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 2, 8, 28).unwrap();
+    ///let long = CompiledFormat::new(&"%B ".repeat(20)).unwrap();
+    ///assert_eq!(long.format_to_smallstr(&value), Err(PersianError::OutputTooLong { capacity: 64 }));
+    /// ```
+    pub fn format_to_smallstr(&self, value: &PersianDateTime) -> Result<SmallStr, PersianError> {
+        let mut out = SmallStr::new();
+        self.write(&mut out, value).map_err(|e| match e {
+            PersianError::WriteFailed => PersianError::OutputTooLong { capacity: CAPACITY },
+            e => e,
+        })?;
+        Ok(out)
+    }
+
+    /// Format a datetime into a buffer kept per thread and hand it to `f`
+    ///
+    /// after the first few calls on a thread this doesn't allocate, like for a logger writing
+    /// each line as it's formatted
+    /// ```rust
+    ///use chrono::{DateTime, Utc};
+    ///use chrono_persian::{CompiledFormat, PersianDateTime};
+    ///
+    ///let format = CompiledFormat::new("%Y/%m/%d").unwrap();
+    ///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+    ///let len = format.format_with(&PersianDateTime::from(utc), |s| s.len());
+    ///assert_eq!(len, 10);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn format_with<R>(&self, value: &PersianDateTime, f: impl FnOnce(&str) -> R) -> R {
+        SCRATCH.with(|scratch| match scratch.try_borrow_mut() {
+            Ok(mut buf) => {
+                buf.clear();
+                self.write(&mut *buf, value)
+                    .expect("writing a datetime to a string can't fail");
+                f(&buf)
+            }
+            // called again from inside `f`
+            Err(_) => f(&self.format(value)),
+        })
+    }
+}
+
+impl FormatFields for PersianDate {
+    fn fields(&self) -> Fields {
+        (self.year(), self.month(), self.day()).fields()
+    }
+}
+
+/// the wall clock, like [`PersianDateTime::date`] and [`PersianDateTime::time`]
+/// ```rust
+///use chrono_persian::{persian_format, PersianDate, PersianDateTime};
+///
+///let mut buf = [0u8; 24];
+///let date = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+///assert_eq!(persian_format!(buf, "%Y/%m/%d", date), Some("1403/08/20"));
+///
+///let a = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 2, 8, 28).unwrap();
+///assert_eq!(persian_format!(buf, "%Y-%m-%d %H:%M:%S", a), Some("1403-08-20 02:08:28"));
+/// ```
+impl FormatFields for PersianDateTime {
+    fn fields(&self) -> Fields {
+        Fields {
+            time: Some((self.hour(), self.minute(), self.second().min(59))),
+            ..self.date().fields()
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use chrono::{Days, Duration};
+use core::fmt;
+use core::ops::Sub;
+use core::str::FromStr;
+
+use crate::{calendar, PersianDate, PersianError};
+
+/// A number of persian calendar months, see [`PersianDate::checked_add`]
+///
+/// unlike a fixed number of days this follows the calendar, so it can't be confused with
+/// 30-day approximations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersianMonths(pub u32);
+
+impl PersianMonths {
+    pub const fn new(months: u32) -> Self {
+        Self(months)
+    }
+}
+
+/// A number of persian calendar years, see [`PersianDate::checked_add`]
+///
+/// 30 esfand moves to 29 esfand in a common year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersianYears(pub u32);
+
+impl PersianYears {
+    pub const fn new(years: u32) -> Self {
+        Self(years)
+    }
+}
+
+/// A number of days, see [`PersianDate::checked_add`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersianDays(pub u64);
+
+impl PersianDays {
+    pub const fn new(days: u64) -> Self {
+        Self(days)
+    }
+}
+
+/// Years, months and days of the persian calendar, written as an iso 8601 duration such as
+/// `P1Y2M10D`
+///
+/// the years and months are added first, clamping the day like [`PersianMonths`], then the
+/// days, subtracting goes in the reverse order, weeks (`P2W`) are read as days and a time
+/// part isn't supported
+/// ```rust
+///use chrono_persian::{CalendarDuration, PersianDate};
+///
+///let term: CalendarDuration = "P1Y2M10D".parse().unwrap();
+///assert_eq!(term, CalendarDuration::new(1, 2, 10));
+///assert_eq!(term.to_string(), "P1Y2M10D");
+///
+///let start = PersianDate::from_ymd_opt(1402, 4, 31).unwrap();
+///assert_eq!(start.checked_add(term).unwrap().to_string(), "1403-07-10");
+///
+///assert_eq!("P2W".parse::<CalendarDuration>().unwrap().to_string(), "P14D");
+///assert_eq!(CalendarDuration::default().to_string(), "P0D");
+///assert!("P1DT2H".parse::<CalendarDuration>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CalendarDuration {
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+}
+
+impl CalendarDuration {
+    pub const fn new(years: u32, months: u32, days: u32) -> Self {
+        Self {
+            years,
+            months,
+            days,
+        }
+    }
+
+    /// the years, months and days from the earlier of the dates to the later, so adding it to
+    /// the earlier one gives the later one
+    /// ```rust
+    ///use chrono_persian::{CalendarDuration, PersianDate};
+    ///
+    ///let a = PersianDate::from_ymd_opt(1402, 4, 31).unwrap();
+    ///let b = PersianDate::from_ymd_opt(1403, 7, 10).unwrap();
+    ///assert_eq!(CalendarDuration::between(a, b), CalendarDuration::new(1, 2, 10));
+    ///assert_eq!(CalendarDuration::between(b, a), CalendarDuration::new(1, 2, 10));
+    /// ```
+    pub fn between(a: PersianDate, b: PersianDate) -> Self {
+        let (from, to) = (a.min(b), a.max(b));
+        let mut months =
+            (to.year() as i64 - from.year() as i64) * 12 + to.month() as i64 - from.month() as i64;
+        if add_months(from, months).is_none_or(|d| d > to) {
+            months -= 1;
+        }
+        let start = add_months(from, months).unwrap_or(from);
+        Self {
+            years: (months / 12) as u32,
+            months: (months % 12) as u32,
+            days: (to - start).num_days() as u32,
+        }
+    }
+
+    fn total_months(&self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+}
+
+impl PersianSpan for CalendarDuration {
+    fn checked_add_to(self, date: PersianDate) -> Option<PersianDate> {
+        let date = add_months(date, self.total_months())?;
+        PersianDays::new(self.days as u64).checked_add_to(date)
+    }
+
+    fn checked_sub_from(self, date: PersianDate) -> Option<PersianDate> {
+        let date = PersianDays::new(self.days as u64).checked_sub_from(date)?;
+        add_months(date, -self.total_months())
+    }
+}
+
+impl fmt::Display for CalendarDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("P")?;
+        if *self == Self::default() {
+            return f.write_str("0D");
+        }
+        for (value, unit) in [(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if value != 0 {
+                write!(f, "{value}{unit}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CalendarDuration {
+    type Err = PersianError;
+
+    /// Parse `PnYnMnWnD`, each part is optional but they must come in that order
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rest = s.strip_prefix('P').ok_or(PersianError::InvalidFormat)?;
+        if rest.is_empty() {
+            return Err(PersianError::InvalidFormat);
+        }
+        let mut duration = Self::default();
+        let mut units = "YMWD";
+        while !rest.is_empty() {
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let value: u32 = rest[..digits]
+                .parse()
+                .map_err(|_| PersianError::InvalidFormat)?;
+            let unit = rest[digits..]
+                .chars()
+                .next()
+                .ok_or(PersianError::InvalidFormat)?;
+            let at = units.find(unit).ok_or(PersianError::InvalidFormat)?;
+            units = &units[at + 1..];
+            let field = match unit {
+                'Y' => &mut duration.years,
+                'M' => &mut duration.months,
+                _ => &mut duration.days,
+            };
+            let value = match unit {
+                'W' => value.checked_mul(7),
+                _ => Some(value),
+            };
+            *field = value
+                .and_then(|v| field.checked_add(v))
+                .ok_or(PersianError::InvalidFormat)?;
+            rest = &rest[digits + 1..];
+        }
+        Ok(duration)
+    }
+}
+
+/// A calendar span that can be added to or subtracted from a [`PersianDate`]
+pub trait PersianSpan {
+    /// returns `None` when the result is out of range
+    fn checked_add_to(self, date: PersianDate) -> Option<PersianDate>;
+
+    /// returns `None` when the result is out of range
+    fn checked_sub_from(self, date: PersianDate) -> Option<PersianDate>;
+}
+
+impl PersianSpan for PersianMonths {
+    fn checked_add_to(self, date: PersianDate) -> Option<PersianDate> {
+        add_months(date, self.0 as i64)
+    }
+
+    fn checked_sub_from(self, date: PersianDate) -> Option<PersianDate> {
+        add_months(date, -(self.0 as i64))
+    }
+}
+
+impl PersianSpan for PersianYears {
+    fn checked_add_to(self, date: PersianDate) -> Option<PersianDate> {
+        add_months(date, self.0 as i64 * 12)
+    }
+
+    fn checked_sub_from(self, date: PersianDate) -> Option<PersianDate> {
+        add_months(date, -(self.0 as i64 * 12))
+    }
+}
+
+impl PersianSpan for PersianDays {
+    fn checked_add_to(self, date: PersianDate) -> Option<PersianDate> {
+        let g = date.to_gregorian()?.checked_add_days(Days::new(self.0))?;
+        Some(PersianDate::from(g))
+    }
+
+    fn checked_sub_from(self, date: PersianDate) -> Option<PersianDate> {
+        let g = date.to_gregorian()?.checked_sub_days(Days::new(self.0))?;
+        Some(PersianDate::from(g))
+    }
+}
+
+impl PersianDate {
+    /// Move a signed number of months, the day is clamped to the end of the target month
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 6, 31).unwrap();
+    ///assert_eq!(date.checked_add_months(1).unwrap().to_string(), "1403-07-30");
+    ///assert_eq!(date.checked_add_months(-7).unwrap().to_string(), "1402-11-30");
+    /// ```
+    pub fn checked_add_months(self, months: i32) -> Option<Self> {
+        add_months(self, months as i64)
+    }
+
+    /// Move a signed number of years, 30 esfand becomes 29 esfand in a common year
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 12, 30).unwrap();
+    ///assert_eq!(date.checked_add_years(1).unwrap().to_string(), "1404-12-29");
+    ///assert_eq!(date.checked_add_years(-4).unwrap().to_string(), "1399-12-30");
+    /// ```
+    pub fn checked_add_years(self, years: i32) -> Option<Self> {
+        add_months(self, years as i64 * 12)
+    }
+
+    /// Move a signed number of days
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_ymd_opt(1403, 12, 30).unwrap();
+    ///assert_eq!(date.checked_add_days(1).unwrap().to_string(), "1404-01-01");
+    ///assert_eq!(date.checked_add_days(-30).unwrap().to_string(), "1403-11-30");
+    /// ```
+    pub fn checked_add_days(self, days: i64) -> Option<Self> {
+        match days {
+            0.. => PersianDays::new(days as u64).checked_add_to(self),
+            _ => PersianDays::new(days.unsigned_abs()).checked_sub_from(self),
+        }
+    }
+}
+
+impl Sub for PersianDate {
+    type Output = Duration;
+
+    /// the whole days from `rhs` to `self`, negative if `rhs` is later
+    /// ```rust
+    ///use chrono::Duration;
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let a = PersianDate::from_ymd_opt(1403, 1, 1).unwrap();
+    ///let b = PersianDate::from_ymd_opt(1404, 1, 1).unwrap();
+    ///assert_eq!(b - a, Duration::days(366));
+    ///assert_eq!((a - b).num_days(), -366);
+    /// ```
+    fn sub(self, rhs: PersianDate) -> Duration {
+        let (Some(a), Some(b)) = (self.to_gregorian(), rhs.to_gregorian()) else {
+            panic!("`PersianDate - PersianDate` out of chrono's range");
+        };
+        a - b
+    }
+}
+
+/// move `months` forward (or backward), clamping the day to the end of the target month
+fn add_months(date: PersianDate, months: i64) -> Option<PersianDate> {
+    let total = date.year() as i64 * 12 + (date.month() as i64 - 1) + months;
+    let year = i32::try_from(total.div_euclid(12)).ok()?;
+    let month = total.rem_euclid(12) as u32 + 1;
+    let day = date.day().min(calendar::days_in_month(year, month));
+    PersianDate::from_ymd_opt(year, month, day)
+}
@@ -0,0 +1,197 @@
+//! The formatting behind a `jalali` filter of a template engine
+//!
+//! template engines hand dates to filters as strings or numbers, [`jalali`] reads either, a
+//! string in the [`Calendar`] the caller names, and formats it with a strftime-like pattern
+//! (see [`crate::CompiledFormat`]), so registering the filter with an engine is a few lines.
+//! with the `tera` feature [`register_tera`] registers it with tera
+//! ```rust
+//!use chrono_persian::template::{jalali, Calendar};
+//!use chrono_persian::Digits;
+//!
+//!let utc = "2024-11-09T22:38:28Z";
+//!assert_eq!(jalali(utc, Calendar::Gregorian, Some("%d %B %Y"), Digits::Persian).unwrap(), "۲۰ آبان ۱۴۰۳");
+//!assert_eq!(jalali("1731191908", Calendar::Gregorian, None, Digits::Latin).unwrap(), "1403/08/20");
+//!let kabul = "1403-08-20T03:08:28+04:30";
+//!assert_eq!(jalali(kabul, Calendar::Jalali, Some("%Y/%m/%d %H:%M"), Digits::Latin).unwrap(), "1403/08/20 03:08");
+//! // the same string read as a gregorian date
+//!assert_eq!(jalali(kabul, Calendar::Gregorian, None, Digits::Latin).unwrap(), "0782/05/28");
+//!assert!(jalali("yesterday", Calendar::Jalali, None, Digits::Latin).is_err());
+//! ```
+//! compiled templates such as askama's call `Display` on what a filter returns, the
+//! [`jalali_display`] and [`jalali_fa`] filters return a [`DisplayPersian`], re-export them in
+//! the template's `filters` module and map the [`PersianError`] to the engine's error
+
+use alloc::string::String;
+use chrono::DateTime;
+use core::fmt;
+#[cfg(feature = "tera")]
+use std::collections::HashMap;
+
+use crate::{CompiledFormat, Digits, IntoInstant, PersianDateTime, PersianError};
+
+/// the pattern of [`jalali`] when none is given
+pub const DEFAULT_FORMAT: &str = "%Y/%m/%d";
+
+/// The calendar a template value string is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Calendar {
+    /// rfc 3339, such as `2024-11-09T22:38:28Z`
+    Gregorian,
+    /// rfc 3339 with jalali components, like [`PersianDateTime::to_rfc3339`] writes
+    Jalali,
+}
+
+/// Read a template value, an rfc 3339 string in `calendar` or unix seconds
+///
+/// gregorian values and timestamps are shown in iran's time, jalali ones keep their offset
+pub fn parse_value(value: &str, calendar: Calendar) -> Result<PersianDateTime, PersianError> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<i64>() {
+        return crate::persian_from_unix_timestamp(secs).ok_or(PersianError::OutOfRange);
+    }
+    match calendar {
+        Calendar::Jalali => PersianDateTime::parse_from_rfc3339(value),
+        Calendar::Gregorian => DateTime::parse_from_rfc3339(value)
+            .map(PersianDateTime::from)
+            .map_err(|_| PersianError::InvalidFormat),
+    }
+}
+
+/// Format a template value, the body of a `jalali` filter, with [`DEFAULT_FORMAT`] when no
+/// pattern is given
+pub fn jalali(
+    value: &str,
+    calendar: Calendar,
+    format: Option<&str>,
+    digits: Digits,
+) -> Result<String, PersianError> {
+    let value = parse_value(value, calendar)?;
+    let format = CompiledFormat::new(format.unwrap_or(DEFAULT_FORMAT))?.with_digits(digits);
+    Ok(format.format(&value))
+}
+
+/// The `jalali` filter of tera (requires the `tera` feature)
+///
+/// it takes a string or a number and the optional arguments `format` (see [`jalali`]),
+/// `calendar`, `"gregorian"` or `"jalali"`, of strings, gregorian by default, and `digits`,
+/// `"persian"` or `"latin"`, persian by default
+#[cfg(feature = "tera")]
+pub fn tera_filter(
+    value: &::tera::Value,
+    args: &HashMap<String, ::tera::Value>,
+) -> ::tera::Result<::tera::Value> {
+    use ::tera::{Error, Value};
+
+    let value = match value {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        v => return Err(Error::msg(format!("jalali can't format {v}"))),
+    };
+    let arg = |name: &str| match args.get(name) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.as_str())),
+        Some(v) => Err(Error::msg(format!(
+            "the `{name}` of jalali must be a string, not {v}"
+        ))),
+    };
+    let calendar = match arg("calendar")? {
+        None | Some("gregorian") => Calendar::Gregorian,
+        Some("jalali") => Calendar::Jalali,
+        Some(c) => return Err(Error::msg(format!("unknown calendar `{c}`"))),
+    };
+    let digits = match arg("digits")? {
+        None | Some("persian") => Digits::Persian,
+        Some("latin") => Digits::Latin,
+        Some(d) => return Err(Error::msg(format!("unknown digits `{d}`"))),
+    };
+    jalali(&value, calendar, arg("format")?, digits)
+        .map(Value::String)
+        .map_err(|e| Error::msg(e.to_string()))
+}
+
+/// Register [`tera_filter`] as `jalali` (requires the `tera` feature)
+/// ```rust
+///use chrono_persian::template::register_tera;
+///
+///let mut tera = tera::Tera::default();
+///register_tera(&mut tera);
+///tera.add_raw_template("date", r#"{{ at | jalali(format="%d %B %Y") }}"#).unwrap();
+///tera.add_raw_template("latin", r#"{{ at | jalali(calendar="jalali", digits="latin") }}"#).unwrap();
+///tera.add_raw_template("bad", r#"{{ at | jalali(calendar="lunar") }}"#).unwrap();
+///
+///let mut context = tera::Context::new();
+///context.insert("at", "2024-11-09T22:38:28Z");
+///assert_eq!(tera.render("date", &context).unwrap(), "۲۰ آبان ۱۴۰۳");
+///assert!(tera.render("bad", &context).is_err());
+///
+///context.insert("at", &1731191908);
+///assert_eq!(tera.render("date", &context).unwrap(), "۲۰ آبان ۱۴۰۳");
+///
+///context.insert("at", "1403-08-20T03:08:28+04:30");
+///assert_eq!(tera.render("latin", &context).unwrap(), "1403/08/20");
+/// ```
+#[cfg(feature = "tera")]
+pub fn register_tera(tera: &mut ::tera::Tera) {
+    tera.register_filter("jalali", tera_filter);
+}
+
+/// A datetime written with a [`CompiledFormat`] when displayed
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::template::{jalali_display, jalali_fa, DisplayPersian};
+///use chrono_persian::{CompiledFormat, PersianDateTime};
+///
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let format = CompiledFormat::new("%d %B").unwrap();
+///assert_eq!(DisplayPersian::new(PersianDateTime::from(utc), format).to_string(), "20 آبان");
+///
+///assert_eq!(jalali_display(&utc, "%Y-%m-%d").unwrap().to_string(), "1403-08-20");
+///assert_eq!(jalali_fa(&utc, "%d %B %Y").unwrap().to_string(), "۲۰ آبان ۱۴۰۳");
+///assert!(jalali_display(&utc, "%Q").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayPersian {
+    value: PersianDateTime,
+    format: CompiledFormat,
+}
+
+impl DisplayPersian {
+    pub fn new(value: PersianDateTime, format: CompiledFormat) -> Self {
+        Self { value, format }
+    }
+}
+
+impl fmt::Display for DisplayPersian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format.write(f, &self.value).map_err(|_| fmt::Error)
+    }
+}
+
+/// A filter writing any kind of instant in iran's time with `pattern` and latin digits
+pub fn jalali_display<T: IntoInstant + Clone>(
+    value: &T,
+    pattern: &str,
+) -> Result<DisplayPersian, PersianError> {
+    display(value, pattern, Digits::Latin)
+}
+
+/// like [`jalali_display`] with persian digits
+pub fn jalali_fa<T: IntoInstant + Clone>(
+    value: &T,
+    pattern: &str,
+) -> Result<DisplayPersian, PersianError> {
+    display(value, pattern, Digits::Persian)
+}
+
+fn display<T: IntoInstant + Clone>(
+    value: &T,
+    pattern: &str,
+    digits: Digits,
+) -> Result<DisplayPersian, PersianError> {
+    let instant = value
+        .clone()
+        .into_instant()
+        .ok_or(PersianError::OutOfRange)?;
+    let format = CompiledFormat::new(pattern)?.with_digits(digits);
+    Ok(DisplayPersian::new(PersianDateTime::from(instant), format))
+}
@@ -0,0 +1,75 @@
+//! Conversions with the `time` crate (requires the `time` feature)
+//!
+//! an `OffsetDateTime` keeps its offset like a chrono `DateTime<FixedOffset>`, a
+//! `PrimitiveDateTime` is read as utc like a `NaiveDateTime`
+//! ```rust
+//!use chrono_persian::{FromPersian, PersianDateTime, ToPersian};
+//!use time::macros::datetime;
+//!
+//!let kabul = datetime!(2024-11-09 23:45:00 +04:30);
+//!let a = kabul.to_persian().unwrap();
+//!assert_eq!(a.to_string(), "1403-08-19 23:45:00 +04:30");
+//!assert_eq!(time::OffsetDateTime::from_persian(&a), Some(kabul));
+//!
+//!let utc = datetime!(2024-11-09 22:38:28.5);
+//!let b = utc.to_persian().unwrap();
+//!assert_eq!(b.to_string(), "1403-08-20 02:08:28.500 +03:30");
+//!assert_eq!(time::PrimitiveDateTime::from_persian(&b), Some(utc));
+//!
+//! // outside the years 1677 to 2262 of nanosecond timestamps
+//!let old = PersianDateTime::from_ymd_hms_opt(1000, 1, 1, 0, 0, 0).unwrap();
+//!let utc = time::PrimitiveDateTime::from_persian(&old).unwrap();
+//!assert_eq!(utc, datetime!(1621-03-20 20:30:00));
+//!assert_eq!(utc.to_persian(), Some(old));
+//! ```
+
+use ::time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+use chrono::{DateTime, FixedOffset};
+
+use crate::{FromPersian, PersianDateTime, ToPersian};
+
+fn to_chrono(value: OffsetDateTime) -> Option<DateTime<FixedOffset>> {
+    let offset = FixedOffset::east_opt(value.offset().whole_seconds())?;
+    let utc = DateTime::from_timestamp(value.unix_timestamp(), value.nanosecond())?;
+    Some(utc.with_timezone(&offset))
+}
+
+fn from_chrono(value: DateTime<FixedOffset>) -> Option<OffsetDateTime> {
+    let offset = UtcOffset::from_whole_seconds(value.offset().local_minus_utc()).ok()?;
+    let utc = OffsetDateTime::from_unix_timestamp(value.timestamp())
+        .ok()?
+        .replace_nanosecond(value.timestamp_subsec_nanos())
+        .ok()?;
+    Some(utc.to_offset(offset))
+}
+
+impl ToPersian for OffsetDateTime {
+    type Output = PersianDateTime;
+
+    fn to_persian(&self) -> Option<PersianDateTime> {
+        to_chrono(*self)?.to_persian()
+    }
+}
+
+impl ToPersian for PrimitiveDateTime {
+    type Output = PersianDateTime;
+
+    fn to_persian(&self) -> Option<PersianDateTime> {
+        Some(PersianDateTime::from(to_chrono(self.assume_utc())?))
+    }
+}
+
+/// keeps the offset of the wall clock
+impl FromPersian for OffsetDateTime {
+    fn from_persian(value: &PersianDateTime) -> Option<Self> {
+        from_chrono(value.to_fixed_offset())
+    }
+}
+
+/// the instant in utc
+impl FromPersian for PrimitiveDateTime {
+    fn from_persian(value: &PersianDateTime) -> Option<Self> {
+        let utc = from_chrono(value.to_fixed_offset())?.to_offset(UtcOffset::UTC);
+        Some(PrimitiveDateTime::new(utc.date(), utc.time()))
+    }
+}
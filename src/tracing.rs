@@ -0,0 +1,41 @@
+//! Persian dates as `tracing` field values (requires the `tracing` feature)
+//!
+//! tracing's `Value` trait is sealed, so `as_field` gives a value tracing records with the
+//! `Display` form, a [`PersianDate`] as `year-month-day` and a [`PersianDateTime`] as
+//! [`PersianDateTime::to_rfc3339`] writes it, which json log pipelines keep as strings
+//! rather than the `Debug` form
+//! ```rust
+//!use chrono_persian::{PersianDate, PersianDateTime};
+//!
+//!let due = PersianDate::from_ymd_opt(1403, 8, 20).unwrap();
+//!let at = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 2, 8, 28).unwrap();
+//!tracing::info!(due = due.as_field(), at = at.as_field(), "invoice created");
+//!assert_eq!(at.as_field().to_string(), "1403-08-20T02:08:28+03:30");
+//! ```
+//! a span can carry both the epoch and the jalali form with [`crate::TimeAttributes`]
+//! ```rust
+//!use chrono_persian::{PersianDateTime, TimeAttributes};
+//!
+//!let at = PersianDateTime::from_ymd_hms_opt(1403, 8, 20, 2, 8, 28).unwrap();
+//!let attributes = TimeAttributes::new(&at);
+//!let span = tracing::info_span!("job", started.epoch_ms = attributes.epoch_ms, started.jalali = %attributes.jalali);
+//!let _guard = span.enter();
+//! ```
+
+use ::tracing::field::{display, DisplayValue};
+
+use crate::{PersianDate, PersianDateTime};
+
+impl PersianDate {
+    /// the date as a tracing field value, `year-month-day`
+    pub fn as_field(&self) -> DisplayValue<Self> {
+        display(*self)
+    }
+}
+
+impl PersianDateTime {
+    /// the datetime as a tracing field value, in rfc 3339 form with the persian date
+    pub fn as_field(&self) -> DisplayValue<String> {
+        display(self.to_rfc3339())
+    }
+}
@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+use chrono::NaiveTime;
+
+use crate::{holidays, BusinessCalendar, PersianDate, PersianDateTime};
+
+/// A market's trading days and session times, in iran's time
+///
+/// [`TradingCalendar::tse`] has the yearly closures of the tehran stock exchange that fall on
+/// fixed solar dates, the [`holidays::SOLAR_HOLIDAYS`], closures on lunar holidays and
+/// unplanned ones are data to add to `days.closures` each year
+/// ```rust
+///use chrono_persian::{PersianDate, TradingCalendar};
+///
+///let mut tse = TradingCalendar::tse();
+/// // tasua and ashura of 1403
+///tse.days.closures.extend([(1403, 4, 25), (1403, 4, 26)].map(|(y, m, d)| PersianDate::from_ymd_opt(y, m, d).unwrap()));
+///
+///let nowruz = PersianDate::from_ymd_opt(1403, 1, 1).unwrap();
+///assert!(!tse.is_trading_day(nowruz));
+///assert_eq!(tse.next_trading_day(nowruz).unwrap().to_string(), "1403-01-05");
+///
+///let tasua = PersianDate::from_ymd_opt(1403, 4, 25).unwrap();
+///assert_eq!(tse.next_trading_day(tasua).unwrap().to_string(), "1403-04-27");
+///
+///let (open, close) = tse.session(PersianDate::from_ymd_opt(1403, 8, 20).unwrap()).unwrap();
+///assert_eq!(open.to_string(), "1403-08-20 09:00:00 +03:30");
+///assert_eq!(close.to_string(), "1403-08-20 12:30:00 +03:30");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TradingCalendar {
+    /// the days the market is open
+    pub days: BusinessCalendar,
+    /// when the session opens
+    pub open: NaiveTime,
+    /// when the session closes
+    pub close: NaiveTime,
+    /// when the session closes on `half_days`
+    pub half_day_close: NaiveTime,
+    /// days trading closes at `half_day_close`
+    pub half_days: Vec<PersianDate>,
+}
+
+impl TradingCalendar {
+    /// The tehran stock exchange, trading saturday to wednesday from 09:00 to 12:30
+    pub fn tse() -> Self {
+        let days = holidays::official_calendar();
+        Self {
+            days,
+            open: NaiveTime::from_hms_opt(9, 0, 0).expect("valid time"),
+            close: NaiveTime::from_hms_opt(12, 30, 0).expect("valid time"),
+            half_day_close: NaiveTime::from_hms_opt(11, 0, 0).expect("valid time"),
+            half_days: Vec::new(),
+        }
+    }
+
+    /// whether the market trades on `date`
+    pub fn is_trading_day(&self, date: PersianDate) -> bool {
+        self.days.is_business_day(date)
+    }
+
+    /// Get the first trading day after `date`, `None` if there is none within a year
+    pub fn next_trading_day(&self, date: PersianDate) -> Option<PersianDate> {
+        self.days.next_business_day(date)
+    }
+
+    /// Get the opening and closing instants of the session on `date`, `None` if the market
+    /// doesn't trade that day
+    pub fn session(&self, date: PersianDate) -> Option<(PersianDateTime, PersianDateTime)> {
+        if !self.is_trading_day(date) {
+            return None;
+        }
+        let close = match self.half_days.contains(&date) {
+            true => self.half_day_close,
+            false => self.close,
+        };
+        Some((date.and_time(self.open)?, date.and_time(close)?))
+    }
+}
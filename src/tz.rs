@@ -0,0 +1,124 @@
+//! Iran's historical utc offsets from the tz database (requires the `tz` feature)
+//!
+//! with this feature every conversion uses the offset iran had at that instant, including the
+//! local mean time (+03:25:44) of the early 20th century and daylight saving time (until 1401)
+//! ```rust
+//!use chrono::NaiveTime;
+//!use chrono_persian::{PersianDate, PersianDateTime, Resolve};
+//!
+//! // clocks were turned back from midnight to 23:00 at the end of 1401/06/30
+//!let date = PersianDate::from_ymd_opt(1401, 6, 30).unwrap();
+//!let time = NaiveTime::from_hms_opt(23, 30, 0).unwrap();
+//!let earliest = PersianDateTime::from_local(date, time, Resolve::Earliest).unwrap();
+//!let latest = PersianDateTime::from_local(date, time, Resolve::Latest).unwrap();
+//!assert_eq!(earliest.to_string(), "1401-06-30 23:30:00 +04:30");
+//!assert_eq!(latest.to_string(), "1401-06-30 23:30:00 +03:30");
+//!assert!(PersianDateTime::from_local(date, time, Resolve::Reject).is_none());
+//!
+//! // and moved forward from midnight to 01:00 at the start of 1401/01/02
+//!let date = PersianDate::from_ymd_opt(1401, 1, 2).unwrap();
+//!let skipped = PersianDateTime::from_local(date, NaiveTime::MIN, Resolve::Earliest).unwrap();
+//!assert_eq!(skipped.to_string(), "1401-01-02 01:00:00 +04:30");
+//!
+//!let start = date.start_of_day_in(&chrono_tz::Asia::Tehran).unwrap();
+//!assert_eq!(start.to_string(), "2022-03-22 01:00:00 +0430");
+//!
+//! // a parsed value in iran's offset of the time keeps following iran's clocks
+//!let summer = PersianDateTime::parse_from_rfc3339("1400-06-01T12:00:00+04:30").unwrap();
+//!let winter = summer + chrono::Duration::days(90);
+//!assert_eq!(winter.to_rfc3339(), "1400-08-30T11:00:00+03:30");
+//! ```
+//! converting to gregorian and back keeps the offset around every transition
+//! ```rust
+//!use chrono::Duration;
+//!use chrono_persian::{tz::offset_history, PersianDateTime};
+//!
+//!for transition in &offset_history()[1..] {
+//!    for nanos in [-1_000_000_000, -1, 0, 1, 500_000_000] {
+//!        let a = PersianDateTime::from(transition.at + Duration::nanoseconds(nanos));
+//!        assert!(a.round_trip_check());
+//!    }
+//!}
+//! ```
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Asia::Tehran;
+use std::sync::LazyLock;
+
+/// A change of iran's utc offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OffsetTransition {
+    /// the first instant with the new offset, the first transition starts at
+    /// [`DateTime::<Utc>::MIN_UTC`]
+    pub at: DateTime<Utc>,
+    pub offset: FixedOffset,
+}
+
+/// transitions are looked for between these years, the tz database has none outside of them
+const SCAN_YEARS: (i32, i32) = (1800, 2100);
+
+static HISTORY: LazyLock<Vec<OffsetTransition>> = LazyLock::new(scan);
+
+/// Get every offset iran has had, in order
+/// ```rust
+///use chrono_persian::tz::offset_history;
+///
+///let history = offset_history();
+///assert_eq!(history[0].offset.to_string(), "+03:25:44");
+///assert_eq!(history[1].at.to_string(), "1935-06-12 20:34:16 UTC");
+///assert_eq!(history[1].offset.to_string(), "+03:30");
+///assert_eq!(history.last().unwrap().offset.to_string(), "+03:30");
+/// ```
+pub fn offset_history() -> &'static [OffsetTransition] {
+    &HISTORY
+}
+
+/// Get iran's offset at an instant
+/// ```rust
+///use chrono::{DateTime, Utc};
+///use chrono_persian::tz::offset_at;
+///
+///let summer = "2020-07-01 12:00:00 UTC".parse::<DateTime<Utc>>().unwrap();
+///assert_eq!(offset_at(&summer).to_string(), "+04:30");
+/// ```
+pub fn offset_at(instant: &DateTime<Utc>) -> FixedOffset {
+    offset_at_naive(&instant.naive_utc())
+}
+
+pub(crate) fn offset_at_naive(utc: &NaiveDateTime) -> FixedOffset {
+    Tehran.offset_from_utc_datetime(utc).fix()
+}
+
+fn scan() -> Vec<OffsetTransition> {
+    let mut day = NaiveDate::from_ymd_opt(SCAN_YEARS.0, 1, 1).expect("valid date");
+    let last = NaiveDate::from_ymd_opt(SCAN_YEARS.1, 1, 1).expect("valid date");
+    let mut offset = offset_at_naive(&day.and_time(Default::default()));
+    let mut history = vec![OffsetTransition {
+        at: DateTime::<Utc>::MIN_UTC,
+        offset,
+    }];
+    while day < last {
+        let next = day.succ_opt().expect("valid date");
+        let end = next.and_time(Default::default());
+        let new = offset_at_naive(&end);
+        if new != offset {
+            // the offset changed during the day, find the second it did
+            let (mut lo, mut hi) = (day.and_time(Default::default()), end);
+            while hi - lo > Duration::seconds(1) {
+                let mid = lo + Duration::seconds((hi - lo).num_seconds() / 2);
+                if offset_at_naive(&mid) == offset {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            history.push(OffsetTransition {
+                at: hi.and_utc(),
+                offset: new,
+            });
+            offset = new;
+        }
+        day = next;
+    }
+    history
+}
@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+
+use crate::{calendar, PersianDate};
+
+/// jalali years that are entirely within chrono's range, dates outside of it need a conversion
+/// to be checked
+const SAFE_YEARS: core::ops::RangeInclusive<i32> = -261000..=261000;
+
+/// jalali years with some dates in chrono's range, the conversion would overflow far past them
+const EDGE_YEARS: core::ops::RangeInclusive<i32> = -263000..=263000;
+
+/// Whether the components form a gregorian date this crate can convert
+/// ```rust
+///use chrono_persian::is_valid_gregorian;
+///
+///assert!(is_valid_gregorian(2024, 2, 29));
+///assert!(!is_valid_gregorian(2023, 2, 29));
+///assert!(!is_valid_gregorian(2024, 13, 1));
+/// ```
+pub fn is_valid_gregorian(year: i32, month: u32, day: u32) -> bool {
+    NaiveDate::from_ymd_opt(year, month, day).is_some()
+}
+
+/// Whether the components form a jalali date this crate can convert, this is the check done by
+/// [`PersianDate::from_ymd_opt`]
+/// ```rust
+///use chrono_persian::is_valid_jalali;
+///
+///assert!(is_valid_jalali(1403, 12, 30));
+///assert!(!is_valid_jalali(1404, 12, 30));
+///assert!(!is_valid_jalali(1403, 7, 31));
+///assert!(!is_valid_jalali(300000, 1, 1));
+///assert!(!is_valid_jalali(i32::MAX, 12, 29));
+/// ```
+pub fn is_valid_jalali(year: i32, month: u32, day: u32) -> bool {
+    if !calendar::is_valid_ymd(year, month, day) {
+        return false;
+    }
+    SAFE_YEARS.contains(&year)
+        || EDGE_YEARS.contains(&year)
+            && PersianDate::from_ymd_unchecked(year, month, day)
+                .to_gregorian()
+                .is_some()
+}
@@ -0,0 +1,125 @@
+//! Functions for the `validator` crate (requires the `validator` feature)
+//!
+//! they work on [`PersianDate`] fields as well as string fields holding a jalali date,
+//! checks that need bounds are meant to be wrapped in a small function of your own
+//! ```rust
+//!use chrono_persian::validate::{jalali_date, jalali_in_past, jalali_in_range};
+//!use chrono_persian::PersianDate;
+//!use validator::{Validate, ValidationError};
+//!
+//!fn in_1403(value: &str) -> Result<(), ValidationError> {
+//!    let min = PersianDate::from_ymd_opt(1403, 1, 1).unwrap();
+//!    let max = PersianDate::from_ymd_opt(1403, 12, 30).unwrap();
+//!    jalali_in_range(value, min, max)
+//!}
+//!
+//!#[derive(Validate)]
+//!struct Form {
+//!    #[validate(custom(function = "jalali_in_past"))]
+//!    birthday: PersianDate,
+//!    #[validate(custom(function = "jalali_date"))]
+//!    hired_at: String,
+//!    #[validate(custom(function = "in_1403"))]
+//!    invoice_date: String,
+//!}
+//!
+//!let form = Form {
+//!    birthday: PersianDate::from_ymd_opt(1370, 5, 12).unwrap(),
+//!    hired_at: "1402/10/01".to_owned(),
+//!    invoice_date: "1404/01/01".to_owned(),
+//!};
+//!let errors = form.validate().unwrap_err();
+//!assert_eq!(errors.field_errors()["invoice_date"][0].code, "jalali_range");
+//!assert_eq!(errors.field_errors().len(), 1);
+//! ```
+
+use std::borrow::Cow;
+use validator::ValidationError;
+
+use crate::{Clock, PersianDate, SystemClock};
+
+/// A field that holds a jalali date
+pub trait JalaliField {
+    /// the date, or a `jalali_date` error if it isn't one
+    fn to_jalali_date(&self) -> Result<PersianDate, ValidationError>;
+}
+
+impl JalaliField for PersianDate {
+    fn to_jalali_date(&self) -> Result<PersianDate, ValidationError> {
+        Ok(*self)
+    }
+}
+
+impl JalaliField for str {
+    fn to_jalali_date(&self) -> Result<PersianDate, ValidationError> {
+        self.parse().map_err(|e: crate::PersianError| {
+            ValidationError::new("jalali_date").with_message(Cow::Owned(e.to_string()))
+        })
+    }
+}
+
+impl JalaliField for String {
+    fn to_jalali_date(&self) -> Result<PersianDate, ValidationError> {
+        self.as_str().to_jalali_date()
+    }
+}
+
+/// Check that the value is a valid jalali date, error code `jalali_date`
+pub fn jalali_date<T: JalaliField + ?Sized>(value: &T) -> Result<(), ValidationError> {
+    value.to_jalali_date().map(|_| ())
+}
+
+/// Check that the date is not before `min`, error code `jalali_min`
+pub fn jalali_min<T: JalaliField + ?Sized>(
+    value: &T,
+    min: PersianDate,
+) -> Result<(), ValidationError> {
+    if value.to_jalali_date()? < min {
+        let mut e = ValidationError::new("jalali_min")
+            .with_message(Cow::Owned(format!("must not be before {min}")));
+        e.add_param(Cow::Borrowed("min"), &min);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Check that the date is not after `max`, error code `jalali_max`
+pub fn jalali_max<T: JalaliField + ?Sized>(
+    value: &T,
+    max: PersianDate,
+) -> Result<(), ValidationError> {
+    if value.to_jalali_date()? > max {
+        let mut e = ValidationError::new("jalali_max")
+            .with_message(Cow::Owned(format!("must not be after {max}")));
+        e.add_param(Cow::Borrowed("max"), &max);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Check that the date is within `min..=max`, error code `jalali_range`
+pub fn jalali_in_range<T: JalaliField + ?Sized>(
+    value: &T,
+    min: PersianDate,
+    max: PersianDate,
+) -> Result<(), ValidationError> {
+    let date = value.to_jalali_date()?;
+    if date < min || date > max {
+        let mut e = ValidationError::new("jalali_range")
+            .with_message(Cow::Owned(format!("must be between {min} and {max}")));
+        e.add_param(Cow::Borrowed("min"), &min);
+        e.add_param(Cow::Borrowed("max"), &max);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Check that the date is before today in iran, error code `jalali_past`
+pub fn jalali_in_past<T: JalaliField + ?Sized>(value: &T) -> Result<(), ValidationError> {
+    if value.to_jalali_date()? >= SystemClock.today_in_tehran() {
+        return Err(
+            ValidationError::new("jalali_past").with_message(Cow::Borrowed("must be in the past"))
+        );
+    }
+    Ok(())
+}
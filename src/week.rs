@@ -0,0 +1,119 @@
+use chrono::{Days, NaiveDate, Weekday};
+
+use crate::{jalali_week_of_year, PersianDate};
+
+/// A week of a persian week-numbering year, see [`PersianDate::iso_week_persian`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PersianIsoWeek {
+    year: i32,
+    week: u32,
+}
+
+impl PersianIsoWeek {
+    /// the week-numbering year, which differs from the calendar year around nowruz
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// the week number, starting from 1
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+}
+
+/// days from saturday, persian weeks start on saturday
+fn days_from_saturday(weekday: Weekday) -> u64 {
+    ((weekday.num_days_from_sunday() + 1) % 7) as u64
+}
+
+/// the saturday starting week 1 of a year, the first week with at least 4 days in the year
+fn first_week_start(year: i32) -> Option<NaiveDate> {
+    let nowruz = PersianDate::from_ymd_opt(year, 1, 1)?;
+    let gregorian = nowruz.to_gregorian()?;
+    match days_from_saturday(nowruz.weekday()) {
+        offset @ 0..=3 => gregorian.checked_sub_days(Days::new(offset)),
+        offset => gregorian.checked_add_days(Days::new(7 - offset)),
+    }
+}
+
+impl PersianDate {
+    /// Get the week of the date, weeks start on saturday and week 1 is the first one with
+    /// at least 4 days in the year (so containing farvardin 4th), like iso 8601 weeks
+    /// ```rust
+    ///use chrono_persian::PersianDate;
+    ///
+    /// // 1404 starts on a friday, so its first days are in the last week of 1403
+    ///let date = PersianDate::from_ymd_opt(1404, 1, 1).unwrap();
+    ///let week = date.iso_week_persian().unwrap();
+    ///assert_eq!((week.year(), week.week()), (1403, 52));
+    ///
+    ///let week = PersianDate::from_ymd_opt(1404, 1, 2).unwrap().iso_week_persian().unwrap();
+    ///assert_eq!((week.year(), week.week()), (1404, 1));
+    /// ```
+    pub fn iso_week_persian(&self) -> Option<PersianIsoWeek> {
+        let date = self.to_gregorian()?;
+        let mut year = self.year();
+        let mut start = first_week_start(year)?;
+        if date < start {
+            year -= 1;
+            start = first_week_start(year)?;
+        } else if first_week_start(year + 1).is_some_and(|next| date >= next) {
+            year += 1;
+            start = first_week_start(year)?;
+        }
+        let week = (date - start).num_days() / 7 + 1;
+        Some(PersianIsoWeek {
+            year,
+            week: week as u32,
+        })
+    }
+
+    /// Make a date from its week-numbering year, week and weekday, `None` if the year doesn't
+    /// have that week, see [`PersianDate::iso_week_persian`]
+    /// ```rust
+    ///use chrono::Weekday;
+    ///use chrono_persian::PersianDate;
+    ///
+    ///let date = PersianDate::from_isoywd_persian(1403, 52, Weekday::Fri).unwrap();
+    ///assert_eq!(date.to_string(), "1404-01-01");
+    ///assert!(PersianDate::from_isoywd_persian(1403, 53, Weekday::Sat).is_none());
+    /// ```
+    pub fn from_isoywd_persian(year: i32, week: u32, weekday: Weekday) -> Option<PersianDate> {
+        let start = first_week_start(year)?;
+        let weeks = (first_week_start(year + 1)? - start).num_days() / 7;
+        if week < 1 || week as i64 > weeks {
+            return None;
+        }
+        let days = (week as u64 - 1) * 7 + days_from_saturday(weekday);
+        Some(PersianDate::from(start.checked_add_days(Days::new(days))?))
+    }
+
+    /// the week of the year, weeks start on saturday and week 1 is the one with farvardin 1st,
+    /// see [`crate::jalali_week_of_year`]
+    pub fn week_of_year(&self) -> u32 {
+        jalali_week_of_year(self.year(), self.month(), self.day()).expect("the date is valid")
+    }
+
+    /// Make a date from its year, [`PersianDate::week_of_year`] and weekday, `None` if the day
+    /// isn't in the year, such as the days of week 1 before nowruz
+    /// ```rust
+    ///use chrono::Weekday;
+    ///use chrono_persian::PersianDate;
+    ///
+    /// // 1404 starts on a friday
+    ///let date = PersianDate::from_week_and_weekday(1404, 2, Weekday::Tue).unwrap();
+    ///assert_eq!(date.to_string(), "1404-01-05");
+    ///assert_eq!((date.week_of_year(), date.weekday()), (2, Weekday::Tue));
+    ///let nowruz = PersianDate::from_week_and_weekday(1404, 1, Weekday::Fri).unwrap();
+    ///assert_eq!(nowruz.to_string(), "1404-01-01");
+    ///assert!(PersianDate::from_week_and_weekday(1404, 1, Weekday::Thu).is_none());
+    ///assert!(PersianDate::from_week_and_weekday(1404, 54, Weekday::Sat).is_none());
+    /// ```
+    pub fn from_week_and_weekday(year: i32, week: u32, weekday: Weekday) -> Option<PersianDate> {
+        let nowruz = PersianDate::from_ymd_opt(year, 1, 1)?;
+        let day = (week.checked_sub(1)? as i64) * 7 + days_from_saturday(weekday) as i64
+            - days_from_saturday(nowruz.weekday()) as i64;
+        let date = nowruz.checked_add_days(day)?;
+        (day >= 0 && date.year() == year).then_some(date)
+    }
+}
@@ -0,0 +1,173 @@
+use chrono::{Datelike, Days, Weekday};
+
+use crate::{PersianDate, PersianDateRange, PersianMonthOfYear};
+
+/// The days of the week that are off, separate from holidays
+///
+/// the default is thursday and friday, the usual weekend in iran
+/// ```rust
+///use chrono::Weekday;
+///use chrono_persian::Weekend;
+///
+///assert!(Weekend::default().contains(Weekday::Thu));
+///assert!(!Weekend::FRIDAY.contains(Weekday::Thu));
+///
+///let workdays: Vec<_> = Weekend::FRIDAY_SATURDAY.workdays().collect();
+///assert_eq!(workdays, [Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Weekend {
+    /// a bit per day, from monday
+    days: u8,
+}
+
+impl Weekend {
+    /// thursday and friday
+    pub const THURSDAY_FRIDAY: Self = Self::from_days(&[Weekday::Thu, Weekday::Fri]);
+    /// only friday
+    pub const FRIDAY: Self = Self::from_days(&[Weekday::Fri]);
+    /// friday and saturday, like in the persian gulf countries
+    pub const FRIDAY_SATURDAY: Self = Self::from_days(&[Weekday::Fri, Weekday::Sat]);
+    /// saturday and sunday, like in europe and the americas
+    pub const SATURDAY_SUNDAY: Self = Self::from_days(&[Weekday::Sat, Weekday::Sun]);
+    /// no day is off
+    pub const NONE: Self = Self { days: 0 };
+
+    /// Make a weekend of the given days
+    pub const fn from_days(days: &[Weekday]) -> Self {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < days.len() {
+            mask |= 1 << days[i].num_days_from_monday();
+            i += 1;
+        }
+        Self { days: mask }
+    }
+
+    /// whether `day` is off
+    pub const fn contains(&self, day: Weekday) -> bool {
+        self.days & 1 << day.num_days_from_monday() != 0
+    }
+
+    /// the days that are off, in the order of a persian week (from saturday)
+    pub fn days(self) -> impl Iterator<Item = Weekday> {
+        persian_week().filter(move |d| self.contains(*d))
+    }
+
+    /// the working days, in the order of a persian week (from saturday)
+    pub fn workdays(self) -> impl Iterator<Item = Weekday> {
+        persian_week().filter(move |d| !self.contains(*d))
+    }
+}
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Self::THURSDAY_FRIDAY
+    }
+}
+
+fn persian_week() -> impl Iterator<Item = Weekday> {
+    core::iter::successors(Some(Weekday::Sat), |d| Some(d.succ())).take(7)
+}
+
+impl PersianDate {
+    /// whether the date falls on the `weekend`
+    pub fn is_weekend(&self, weekend: Weekend) -> bool {
+        weekend.contains(self.weekday())
+    }
+
+    /// Move forward by `days` working days, skipping the `weekend`
+    ///
+    /// a date on the weekend first moves to the next working day, `None` when out of range or
+    /// when no day of the week is a working day
+    /// ```rust
+    ///use chrono_persian::{PersianDate, Weekend};
+    ///
+    /// // a wednesday
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 23).unwrap();
+    ///let a = date.checked_add_business_days(1, Weekend::default()).unwrap();
+    ///assert_eq!(a.to_string(), "1403-08-26");
+    ///
+    ///let b = date.checked_add_business_days(1, Weekend::FRIDAY).unwrap();
+    ///assert_eq!(b.to_string(), "1403-08-24");
+    /// ```
+    pub fn checked_add_business_days(self, days: u32, weekend: Weekend) -> Option<Self> {
+        // a week without working days never gets anywhere
+        weekend.workdays().next()?;
+        let mut date = self.to_gregorian()?;
+        let mut left = days;
+        while weekend.contains(date.weekday()) {
+            date = date.checked_add_days(Days::new(1))?;
+        }
+        while left > 0 {
+            date = date.checked_add_days(Days::new(1))?;
+            if !weekend.contains(date.weekday()) {
+                left -= 1;
+            }
+        }
+        Some(Self::from(date))
+    }
+
+    /// Iterate over the working days from this date on, skipping the `weekend`, like
+    /// [`PersianDate::iter_days`]
+    /// ```rust
+    ///use chrono_persian::{PersianDate, Weekend};
+    ///
+    /// // a wednesday
+    ///let date = PersianDate::from_ymd_opt(1403, 8, 23).unwrap();
+    ///let days: Vec<_> = date.iter_working_days(Weekend::default()).take(2).map(|d| d.to_string()).collect();
+    ///assert_eq!(days, ["1403-08-23", "1403-08-26"]);
+    /// ```
+    pub fn iter_working_days(&self, weekend: Weekend) -> impl Iterator<Item = PersianDate> {
+        self.iter_days().filter(move |d| !d.is_weekend(weekend))
+    }
+}
+
+impl PersianDateRange {
+    /// Count the working days in the range, skipping the `weekend`
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianDateRange, Weekend};
+    ///
+    ///let aban = PersianDateRange::new(
+    ///    PersianDate::from_ymd_opt(1403, 8, 1).unwrap(),
+    ///    PersianDate::from_ymd_opt(1403, 8, 30).unwrap(),
+    ///)
+    ///.unwrap();
+    ///assert_eq!(aban.business_days(Weekend::default()), 22);
+    ///assert_eq!(aban.business_days(Weekend::FRIDAY), 26);
+    /// ```
+    pub fn business_days(&self, weekend: Weekend) -> u32 {
+        self.working_days(weekend).count() as u32
+    }
+
+    /// the days of the range that aren't on the `weekend`, in order
+    /// ```rust
+    ///use chrono_persian::{PersianDate, PersianDateRange, Weekend};
+    ///
+    ///let week = PersianDateRange::new(
+    ///    PersianDate::from_ymd_opt(1403, 8, 19).unwrap(),
+    ///    PersianDate::from_ymd_opt(1403, 8, 25).unwrap(),
+    ///)
+    ///.unwrap();
+    ///let days: Vec<_> = week.working_days(Weekend::FRIDAY_SATURDAY).map(|d| d.day()).collect();
+    ///assert_eq!(days, [20, 21, 22, 23, 24]);
+    ///assert_eq!(week.working_days(Weekend::default()).next_back().unwrap().day(), 23);
+    /// ```
+    pub fn working_days(&self, weekend: Weekend) -> impl DoubleEndedIterator<Item = PersianDate> {
+        self.days().filter(move |d| !d.is_weekend(weekend))
+    }
+}
+
+impl PersianMonthOfYear {
+    /// the days of the month that aren't on the `weekend`, in order
+    /// ```rust
+    ///use chrono_persian::{PersianMonthOfYear, Weekend};
+    ///
+    ///let aban = PersianMonthOfYear::new(1403, 8).unwrap();
+    ///assert_eq!(aban.working_days(Weekend::default()).count(), 22);
+    ///assert_eq!(aban.working_days(Weekend::NONE).count(), 30);
+    /// ```
+    pub fn working_days(&self, weekend: Weekend) -> impl DoubleEndedIterator<Item = PersianDate> {
+        self.days().filter(move |d| !d.is_weekend(weekend))
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::{DateTime, FixedOffset, Offset, TimeZone};
+
+use crate::PersianDateTime;
+
+/// Convert an instant to the persian date and time at an offset or in a zone other than
+/// iran's, such as afghanistan's +04:30
+///
+/// the result is pinned to the offset the zone had at the instant, see
+/// [`PersianDateTime::with_offset`]. iran's own conversions follow its historical daylight
+/// saving time with the `tz` feature
+/// ```rust
+///use chrono::{DateTime, FixedOffset, Utc};
+///use chrono_persian::ToPersianInZone;
+///
+///let utc = "2024-11-09 22:38:28 UTC".parse::<DateTime<Utc>>().unwrap();
+///let kabul = FixedOffset::east_opt(4 * 3600 + 1800).unwrap();
+///let a = utc.to_persian_with_offset(kabul);
+///assert_eq!(a.to_string(), "1403-08-20 03:08:28 +04:30");
+///
+///let b = utc.to_persian_in_tz(&Utc);
+///assert_eq!(b.to_string(), "1403-08-19 22:38:28 +00:00");
+///assert_eq!(a, b);
+/// ```
+pub trait ToPersianInZone {
+    /// the wall clock at `offset`
+    fn to_persian_with_offset(&self, offset: FixedOffset) -> PersianDateTime;
+
+    /// the wall clock in `tz`, at the offset it has at the instant
+    fn to_persian_in_tz<Z: TimeZone>(&self, tz: &Z) -> PersianDateTime;
+}
+
+impl<Tz: TimeZone> ToPersianInZone for DateTime<Tz> {
+    fn to_persian_with_offset(&self, offset: FixedOffset) -> PersianDateTime {
+        PersianDateTime::from(self.clone()).with_offset(offset)
+    }
+
+    fn to_persian_in_tz<Z: TimeZone>(&self, tz: &Z) -> PersianDateTime {
+        self.to_persian_with_offset(self.with_timezone(tz).offset().fix())
+    }
+}